@@ -1,19 +1,63 @@
 use anyhow::Result;
 use crossterm::{
     cursor,
-    event::{DisableMouseCapture, KeyCode, KeyEvent},
+    event::{DisableMouseCapture, KeyEvent},
     execute,
     terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
 };
+use clap::ValueEnum;
 use ratatui::{backend::CrosstermBackend, Terminal};
 
 use crate::{
-    cli::Args, state::{EachFrameImpl, ShouldRender, State}, tui::{Event, Tui}, ui::ui, widget::AsWeatherWidget
+    cli::Args, config, state::{timer::{Timer, TimerMode}, EachFrameImpl, ShouldRender, State}, tui::{Event, Tui}, ui::ui, util::{ring_bell, save_screenshot, spawn_command}, widget::AsWeatherWidget
 };
 
+/// speeds switched to by keys
+const SPEEDS: [f32; 9] = [0.1, 0.25, 0.5, 0.75, 1.0, 1.5, 2.0, 4.0, 8.0];
+
+/// what a key does, named in kebab-case in `[keys]` of config
+#[derive(Copy, Clone, Debug, PartialEq, Eq, ValueEnum)]
+pub enum Action {
+    Quit,
+    /// next effect
+    NextMode,
+    LevelUp,
+    LevelDown,
+    NextWind,
+    ToggleFps,
+    /// blink colon or not
+    ToggleBlink,
+    NextTimerMode,
+    /// pause or resume weather and timer motion, the clock goes on
+    Pause,
+    /// advance a paused animation by a tick
+    Step,
+    SpeedUp,
+    SpeedDown,
+    /// show or hide keys and settings
+    Help,
+    /// save the screen as text to `tenki-<date>-<time>.txt`
+    Screenshot,
+    /// start or stop the stopwatch, pause or resume the pomodoro
+    StartStop,
+    /// lap of stopwatch
+    Lap,
+    /// reset stopwatch
+    Reset,
+    /// skip pomodoro phase
+    Skip,
+    /// dismiss ringing alarm until the next day
+    Dismiss,
+    /// snooze ringing alarm
+    Snooze,
+}
+
 #[derive(Copy, Clone)]
 pub struct AppRuntimeInfo {
     pub fps: usize,
+    /// help overlay is shown
+    pub help: bool,
+    pub paused: bool,
 }
 
 pub struct App<T> {
@@ -23,33 +67,45 @@ pub struct App<T> {
     should_quit: bool,
     should_render: ShouldRender,
     args: Args,
+    /// args of command line and config, without changes by keys
+    loaded: Args,
     frame_in_second: usize,
     runtime_info: AppRuntimeInfo,
+    /// a tick of paused animation is requested
+    step: bool,
+    /// the next render is saved to a file
+    screenshot: bool,
 }
 
 impl<T> App<T>
 where
-    T: EachFrameImpl + AsWeatherWidget,
+    T: EachFrameImpl + AsWeatherWidget + for<'a> From<&'a Args>,
 {
     pub fn new(args: Args, weather: T) -> Result<Self> {
+        let backend = CrosstermBackend::new(std::io::stdout());
+        let mut terminal = Terminal::new(backend)?;
+        let state = State::new(terminal.size()?, weather, &args)?;
+        let mut tui = Tui::new(args.fps as f64, args.tps as f64)?;
+        if let Some(path) = &args.config {
+            tui.watch(path.clone());
+        }
+
         // setup terminal
         enable_raw_mode()?;
-        let mut stdout = std::io::stdout();
-        execute!(stdout, EnterAlternateScreen)?;
-
-        let backend = CrosstermBackend::new(stdout);
-        let terminal = Terminal::new(backend)?;
-        let state = State::new(terminal.size()?, weather, args);
+        execute!(terminal.backend_mut(), EnterAlternateScreen)?;
 
         Ok(Self {
             terminal,
             state,
+            tui,
+            loaded: args.clone(),
             args,
-            tui: Tui::new(args.fps as f64, args.tps as f64)?,
             should_quit: false,
             should_render: ShouldRender::Render,
             frame_in_second: 0,
-            runtime_info: AppRuntimeInfo { fps: 0 },
+            runtime_info: AppRuntimeInfo { fps: 0, help: false, paused: false },
+            step: false,
+            screenshot: false,
         })
     }
 
@@ -67,6 +123,7 @@ where
                     Tick => self.on_tick(),
                     Timer => self.on_timer(),
                     Resize(columns, rows) => self.on_resize(columns, rows),
+                    Reload => self.on_reload(),
                 };
             };
 
@@ -79,19 +136,176 @@ where
     }
 
     fn handle_keyboard(&mut self, key: KeyEvent) {
-        match key.code {
-            KeyCode::Esc | KeyCode::Char('q') | KeyCode::Char('Q') => self.should_quit = true,
-            _ => {}
+        let actions = self.args.keys.actions(key).collect::<Vec<_>>();
+        if actions.contains(&Action::Quit) {
+            self.should_quit = true;
+        } else if self.state.alarm.is_ringing() {
+            // other keys wait until the alarm is dismissed or snoozed
+            if actions.iter().any(|action| self.state.alarm.handle_action(*action)) {
+                self.rebuild_weather();
+            }
+        } else if actions.iter().any(|action| self.state.handle_action(*action)) {
+            // actions of stopwatch and pomodoro come first, `space` is shared with pause
+            self.should_render = ShouldRender::Render;
+            self.on_transition();
+        } else if let Some(action) = actions.first() {
+            self.on_action(*action);
+            self.should_render = ShouldRender::Render;
+        }
+    }
+
+    /// change effect and timer while running
+    fn on_action(&mut self, action: Action) {
+        match action {
+            Action::Quit => self.should_quit = true,
+            Action::NextMode => {
+                self.args.mode = self.args.mode.next();
+                self.rebuild_weather();
+            }
+            Action::LevelUp => self.change_level(true),
+            Action::LevelDown => self.change_level(false),
+            Action::NextWind => {
+                self.args.wind = self.args.wind.next();
+                self.rebuild_weather();
+            }
+            Action::ToggleFps => self.args.show_fps = !self.args.show_fps,
+            Action::ToggleBlink => {
+                self.args.blink_colon = !self.args.blink_colon;
+                self.state.timer_state.colon.set_blink(self.args.blink_colon);
+            }
+            Action::NextTimerMode => {
+                self.args.timer_mode = TimerMode::next(self.args.timer_mode);
+                self.state.timer_state.set_mode(self.args.timer_mode.map(|mode| mode.into()));
+            }
+            Action::Pause => self.runtime_info.paused = !self.runtime_info.paused,
+            Action::Step => {
+                self.runtime_info.paused = true;
+                self.step = true;
+            }
+            Action::SpeedUp => self.change_speed(true),
+            Action::SpeedDown => self.change_speed(false),
+            Action::Help => self.runtime_info.help = !self.runtime_info.help,
+            Action::Screenshot => self.screenshot = true,
+            // handled by stopwatch, pomodoro and alarm
+            Action::StartStop | Action::Lap | Action::Reset | Action::Skip | Action::Dismiss | Action::Snooze => (),
         }
     }
 
+    /// next speed faster or slower than the current one
+    fn change_speed(&mut self, up: bool) {
+        let speed = self.args.speed;
+        let next = if up {
+            SPEEDS.iter().find(|s| **s > speed)
+        } else {
+            SPEEDS.iter().rev().find(|s| **s < speed)
+        };
+
+        if let Some(speed) = next {
+            self.args.speed = *speed;
+            self.state.speed = *speed;
+        }
+    }
+
+    /// raise or lower level by a tenth of it, keeping it in [4-1000]
+    fn change_level(&mut self, up: bool) {
+        let level = self.args.level.unwrap_or(self.args.mode.default_level());
+        let step = (level / 10).max(1);
+        let level = if up { level.saturating_add(step) } else { level.saturating_sub(step) };
+
+        self.args.level = Some(level.clamp(4, 1000));
+        self.rebuild_weather();
+    }
+
+    /// swap weather and notify when pomodoro phase switched
+    fn on_transition(&mut self) {
+        let Some(transition) = self.state.take_transition() else {
+            return;
+        };
+
+        self.rebuild_weather();
+        ring_bell();
+        if let Some(cmd) = &self.args.pomodoro_hook {
+            spawn_command(cmd, [
+                ("TENKI_PHASE", transition.phase.to_string()),
+                ("TENKI_CYCLE", transition.cycle.to_string()),
+            ]);
+        }
+    }
+
+    /// rebuild weather from args, pomodoro phase and ringing alarm
+    fn rebuild_weather(&mut self) {
+        let mode = self.state.source
+            .phase()
+            .map_or(self.args.mode, |phase| self.args.phase_mode(phase));
+
+        // intensify the effect while alarm is ringing, a quarter of the level kept in [4-1000] and never weaker
+        let level = if self.state.alarm.is_ringing() {
+            let level = self.args.level.unwrap_or(mode.default_level());
+            Some((level / 4).max(4).min(level))
+        } else {
+            self.args.level
+        };
+
+        self.state.weather = T::from(&Args {
+            mode,
+            level,
+            ..self.args.clone()
+        });
+        self.should_render = ShouldRender::Render;
+    }
+
     fn on_resize(&mut self, columns: u16, rows: u16) {
         self.state.on_resize(columns, rows);
         self.should_render = ShouldRender::Render;
     }
 
+    /// apply changed config, an invalid config is ignored until it is fixed
+    fn on_reload(&mut self) {
+        let Ok(loaded) = config::load_args() else {
+            return;
+        };
+
+        let args = self.keep_changes_by_keys(&loaded);
+        if self.state.apply_args(&self.args, &args).is_err() {
+            return;
+        }
+
+        if (args.fps, args.tps) != (self.args.fps, self.args.tps) {
+            self.tui.set_rates(args.fps as f64, args.tps as f64);
+        }
+
+        self.loaded = loaded;
+        self.args = args;
+        self.rebuild_weather();
+    }
+
+    /// options changed by keys keep their values unless the reloaded config changed them too
+    fn keep_changes_by_keys(&self, loaded: &Args) -> Args {
+        fn keep<V: PartialEq + Copy>(old: V, loaded: V, current: V) -> V {
+            if old == loaded { current } else { loaded }
+        }
+
+        let (old, current) = (&self.loaded, &self.args);
+        Args {
+            mode: keep(old.mode, loaded.mode, current.mode),
+            level: keep(old.level, loaded.level, current.level),
+            wind: keep(old.wind, loaded.wind, current.wind),
+            speed: keep(old.speed, loaded.speed, current.speed),
+            show_fps: keep(old.show_fps, loaded.show_fps, current.show_fps),
+            blink_colon: keep(old.blink_colon, loaded.blink_colon, current.blink_colon),
+            timer_mode: keep(old.timer_mode, loaded.timer_mode, current.timer_mode),
+            ..loaded.clone()
+        }
+    }
+
     fn on_tick(&mut self) {
-        self.should_render = self.should_render.or(self.state.tick());
+        let should_render = match (self.runtime_info.paused, std::mem::take(&mut self.step)) {
+            (true, true) => self.state.tick_step(),
+            (true, false) => self.state.tick_paused(),
+            (false, _) => self.state.tick(),
+        };
+        self.should_render = self.should_render.or(should_render);
+        self.on_transition();
     }
 
     fn on_render(&mut self) -> anyhow::Result<()> {
@@ -103,18 +317,61 @@ where
 
         if self.should_render.is_render() {
             self.should_render = ShouldRender::Skip;
-            self.terminal.draw(|f| ui(f, &mut self.state, self.args, self.runtime_info))?;
+            let mut screen = None;
+            self.terminal.draw(|f| {
+                ui(f, &mut self.state, &self.args, self.runtime_info);
+                if std::mem::take(&mut self.screenshot) {
+                    screen = Some(f.buffer_mut().clone());
+                }
+            })?;
+
+            // a failed screenshot rings the bell, the clock goes on
+            if screen.is_some_and(|buf| save_screenshot(&buf).is_err()) {
+                ring_bell();
+            }
         }
 
         Ok(())
     }
 
     fn on_timer(&mut self) {
-        self.state.tick_timer();
+        self.state.tick_timer(self.runtime_info.paused);
+        self.on_alarm();
+        self.on_chime();
         self.runtime_info.fps = self.frame_in_second;
         self.frame_in_second = 0;
         self.should_render = ShouldRender::Render;
     }
+
+    /// ring the bell each second until the alarm is dismissed or snoozed
+    fn on_alarm(&mut self) {
+        if self.state.alarm.check() {
+            self.rebuild_weather();
+            if let Some(cmd) = &self.args.alarm_command {
+                spawn_command(cmd, []);
+            }
+        }
+
+        if self.state.alarm.is_ringing() {
+            ring_bell();
+        }
+    }
+
+    fn on_chime(&mut self) {
+        let now = Timer::new();
+        if self.state.chime.check(now) {
+            if let Some(cmd) = &self.args.chime_command {
+                spawn_command(cmd, [
+                    ("TENKI_HOUR", now.hours.to_string()),
+                    ("TENKI_MINUTE", now.minutes.to_string()),
+                ]);
+            }
+        }
+
+        if self.state.chime.take_ring() {
+            ring_bell();
+        }
+    }
 }
 
 impl<T> Drop for App<T> {
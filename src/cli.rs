@@ -1,23 +1,105 @@
-use std::str::FromStr;
+use std::{path::PathBuf, str::FromStr};
 
+use chrono::NaiveTime;
 use clap::Parser;
 use clap_num::number_range;
 use ratatui::style::Color;
 
-use crate::state::{timer::TimerMode, wind::WindMode, Mode};
+use crate::{
+    keymap::Keymap,
+    state::{burn_in::NightHours, chime::ChimeInterval, pomodoro::Phase, timer::{Anchor, TimerMode}, wind::WindMode, Mode},
+    theme::DEFAULT_THEME,
+    widget::{
+        timer::{
+            color::{GradientAxis, TimerColor},
+            digital::{Scale, TimerGlyphs},
+            font::DEFAULT_FONT,
+            pixel::PixelMode,
+            progress::Progress,
+            words::WordLang,
+            ClockFace,
+        },
+        weather::{GlyphSet, WeatherStyle, WeatherStyles},
+    },
+};
 
-#[derive(Parser, Clone, Copy)]
+#[derive(Parser, Clone)]
 #[command(author, version, about, long_about = None)]
 pub struct Args {
+    /// config file, options in it are overridden by command line [default: $XDG_CONFIG_HOME/tenki/config.toml]
+    #[arg(long)]
+    pub config: Option<PathBuf>,
+
+    /// profile of config file overriding its options, e.g. `[profile.night]` for night
+    #[arg(long)]
+    pub profile: Option<String>,
+
+    /// colors and glyphs, a bundled theme [default, solarized, nord, tokyo-night, mono]
+    /// or `<name>.toml` in $XDG_CONFIG_HOME/tenki/themes
+    #[arg(long, default_value = DEFAULT_THEME)]
+    pub theme: String,
+
     #[arg(long, default_value_t = Mode::Rain)]
     pub mode: Mode,
 
     #[arg(long)]
     pub timer_mode: Option<TimerMode>,
 
-    /// color of the effect. [red, green, blue, yellow, cyan, magenta, white, black]
-    #[arg(long, value_parser = Color::from_str, default_value = "white")]
-    pub timer_color: Color,
+    /// where the timer is placed, modes moving the timer start from here
+    #[arg(long, default_value_t = Anchor::Center)]
+    pub timer_position: Anchor,
+
+    /// offset of the timer from its position in cells, e.g. 4,-2
+    #[arg(long, value_parser = parse_offset, default_value = "0,0", allow_hyphen_values = true)]
+    pub timer_offset: (i16, i16),
+
+    /// cells kept between the timer and the edges of screen at its position
+    #[arg(long, default_value_t = 0)]
+    pub timer_margin: u16,
+
+    /// face of the timer
+    #[arg(long, default_value_t = ClockFace::Digital)]
+    pub clock: ClockFace,
+
+    /// font of digital clock, a bundled font [block, thin, wide, seven-segment, small, round, smooth] or a font file.
+    /// font file is glyphs of 0 to 9 and optional colon separated by blank lines with `#` marking lit cells,
+    /// or a FIGlet font with .flf extension
+    #[arg(long, default_value = DEFAULT_FONT)]
+    pub font: String,
+
+    /// pixels of digital clock font in a cell. round and smooth fonts are made for half and braille
+    #[arg(long, default_value_t = PixelMode::Block)]
+    pub pixel: PixelMode,
+
+    /// scale of digital clock, an integer multiple or auto to fill --scale-fraction of the screen.
+    /// it falls back to a compact font or plain text when the screen is too small
+    #[arg(long, value_parser = parse_scale, default_value = "1")]
+    pub scale: Scale,
+
+    /// fraction of the screen filled by auto scaled digital clock [0.1-1.0]
+    #[arg(long, value_parser = fraction_range, default_value_t = 0.5)]
+    pub scale_fraction: f32,
+
+    /// language of words clock
+    #[arg(long, default_value_t = WordLang::En)]
+    pub clock_lang: WordLang,
+
+    /// color of unlit letters of words clock
+    #[arg(long, value_parser = Color::from_str, default_value = "darkgray")]
+    pub dim_color: Color,
+
+    /// color of the timer. a color [red, green, blue, yellow, cyan, magenta, white, black, #rrggbb],
+    /// rainbow, time for cooler at night and warmer at noon, or a gradient like #ff0000..#0000ff
+    #[arg(long, value_parser = TimerColor::from_str, default_value = "white")]
+    pub timer_color: TimerColor,
+
+    /// progress of seconds in the minute around the timer
+    #[arg(long)]
+    pub progress: Option<Progress>,
+
+    /// direction of timer color gradient
+    #[arg(long, default_value_t = GradientAxis::Columns)]
+    pub timer_gradient: GradientAxis,
 
     /// frame per second
     #[arg(short, long, value_parser = process_rate_range, default_value_t = 60)]
@@ -27,6 +109,10 @@ pub struct Args {
     #[arg(short, long, value_parser = process_rate_range, default_value_t = 60)]
     pub tps: u8,
 
+    /// speed of weather and timer motion, e.g. 0.5 for slow motion [0.1-8.0]
+    #[arg(long, value_parser = speed_range, default_value_t = 1.0)]
+    pub speed: f32,
+
     /// effect level, The lower, the stronger [4-1000]
     #[arg(short, long, value_parser = level_range)]
     pub level: Option<u16>,
@@ -35,6 +121,18 @@ pub struct Args {
     #[arg(long, value_parser = WindMode::from_str, default_value = "random")]
     pub wind: WindMode,
 
+    /// glyphs of rain, one for all drops or three for fast, normal and slow drops. slow drops in wind use tails of the theme
+    #[arg(long, value_parser = parse_rain_chars)]
+    pub rain_chars: Option<[char; 3]>,
+
+    /// glyphs of snow, each flake picks one at random, e.g. "*❄❅❆" [1-8 glyphs]
+    #[arg(long, value_parser = parse_glyph_set)]
+    pub snow_chars: Option<GlyphSet>,
+
+    /// glyphs of meteor heads, each meteor picks one at random [1-8 glyphs]
+    #[arg(long, value_parser = parse_glyph_set)]
+    pub meteor_head: Option<GlyphSet>,
+
     /// show fps at right-top in screen
     #[arg(long)]
     pub show_fps: bool,
@@ -42,6 +140,129 @@ pub struct Args {
     /// blinking colon of timer
     #[arg(long)]
     pub blink_colon: bool,
+
+    /// stopwatch mode. [space] start/stop, [l] lap, [r] reset, [p] pauses the animation
+    #[arg(long)]
+    pub stopwatch: bool,
+
+    /// pomodoro mode. [space] pause/resume, [s] skip phase, [p] pauses the animation
+    #[arg(long, conflicts_with = "stopwatch")]
+    pub pomodoro: bool,
+
+    /// minutes of pomodoro work session
+    #[arg(long, value_parser = minutes_range, default_value_t = 25)]
+    pub work: u16,
+
+    /// minutes of pomodoro short break
+    #[arg(long, value_parser = minutes_range, default_value_t = 5)]
+    pub short_break: u16,
+
+    /// minutes of pomodoro long break
+    #[arg(long, value_parser = minutes_range, default_value_t = 15)]
+    pub long_break: u16,
+
+    /// work sessions before a pomodoro long break
+    #[arg(long, value_parser = interval_range, default_value_t = 4)]
+    pub long_break_interval: u16,
+
+    /// effect of pomodoro breaks, work sessions use --mode
+    #[arg(long, default_value_t = Mode::Snow)]
+    pub break_mode: Mode,
+
+    /// timer color of pomodoro breaks, work sessions use --timer-color
+    #[arg(long, value_parser = Color::from_str, default_value = "green")]
+    pub break_color: Color,
+
+    /// command run at each pomodoro phase transition, with TENKI_PHASE and TENKI_CYCLE set
+    #[arg(long)]
+    pub pomodoro_hook: Option<String>,
+
+    /// alarm at HH:MM, can be repeated. [enter] dismiss, [z] snooze
+    #[arg(long, value_parser = parse_alarm)]
+    pub alarm: Vec<NaiveTime>,
+
+    /// minutes of alarm snooze
+    #[arg(long, value_parser = minutes_range, default_value_t = 5)]
+    pub snooze: u16,
+
+    /// flashing timer color of ringing alarm
+    #[arg(long, value_parser = Color::from_str, default_value = "red")]
+    pub alarm_color: Color,
+
+    /// command run when alarm starts ringing
+    #[arg(long)]
+    pub alarm_command: Option<String>,
+
+    /// ring the bell and pulse the timer at each interval of wall clock
+    #[arg(long)]
+    pub chime: Option<ChimeInterval>,
+
+    /// ring the bell as many times as the hour on the hour, like a grandfather clock
+    #[arg(long)]
+    pub chime_count: bool,
+
+    /// pulsing timer color of chime
+    #[arg(long, value_parser = Color::from_str, default_value = "yellow")]
+    pub chime_color: Color,
+
+    /// command run at each chime, with TENKI_HOUR and TENKI_MINUTE set
+    #[arg(long)]
+    pub chime_command: Option<String>,
+
+    /// shift the timer every few minutes and cycle its color slowly, for always-on displays
+    #[arg(long)]
+    pub burn_in_protection: bool,
+
+    /// dim the screen during HH:MM-HH:MM with burn-in protection, e.g. 22:00-06:00
+    #[arg(long, value_parser = parse_night_hours, requires = "burn_in_protection")]
+    pub night_hours: Option<NightHours>,
+
+    /// brightness of dimmed screen at night [0.1-1.0]
+    #[arg(long, value_parser = fraction_range, default_value_t = 0.3)]
+    pub night_brightness: f32,
+
+    /// colors and glyphs of modes from the theme and config file
+    #[arg(skip)]
+    pub styles: WeatherStyles,
+
+    /// glyphs of digital clock from the theme
+    #[arg(skip)]
+    pub timer_glyphs: TimerGlyphs,
+
+    /// keys of actions from config file
+    #[arg(skip)]
+    pub keys: Keymap,
+}
+
+impl Args {
+    pub fn phase_mode(&self, phase: Phase) -> Mode {
+        if phase.is_break() {
+            self.break_mode
+        } else {
+            self.mode
+        }
+    }
+
+    pub fn phase_color(&self, phase: Phase) -> TimerColor {
+        if phase.is_break() {
+            TimerColor::Solid(self.break_color)
+        } else {
+            self.timer_color
+        }
+    }
+
+    /// style of the mode from the theme and config, with glyphs given by command line
+    pub fn style(&self, mode: Mode) -> WeatherStyle {
+        let mut style = self.styles.get(mode);
+        match mode {
+            Mode::Rain => style.glyphs = self.rain_chars.unwrap_or(style.glyphs),
+            Mode::Snow => style.variants = self.snow_chars.unwrap_or(style.variants),
+            Mode::Meteor => style.variants = self.meteor_head.unwrap_or(style.variants),
+            Mode::Disable => {}
+        }
+
+        style
+    }
 }
 
 fn process_rate_range(s: &str) -> Result<u8, String> {
@@ -51,3 +272,66 @@ fn process_rate_range(s: &str) -> Result<u8, String> {
 fn level_range(s: &str) -> Result<u16, String> {
     number_range(s, 0, 1000)
 }
+
+fn parse_alarm(s: &str) -> Result<NaiveTime, String> {
+    NaiveTime::parse_from_str(s, "%H:%M").map_err(|_| format!("invalid alarm time `{s}`, expected HH:MM"))
+}
+
+fn parse_night_hours(s: &str) -> Result<NightHours, String> {
+    let err = || format!("invalid night hours `{s}`, expected HH:MM-HH:MM");
+    let (start, end) = s.split_once('-').ok_or_else(err)?;
+    let parse = |t: &str| NaiveTime::parse_from_str(t, "%H:%M").map_err(|_| err());
+    Ok(NightHours(parse(start)?, parse(end)?))
+}
+
+fn parse_offset(s: &str) -> Result<(i16, i16), String> {
+    s.split_once(',')
+        .and_then(|(x, y)| Some((x.trim().parse().ok()?, y.trim().parse().ok()?)))
+        .ok_or_else(|| format!("invalid offset `{s}`, expected X,Y"))
+}
+
+fn parse_rain_chars(s: &str) -> Result<[char; 3], String> {
+    match s.chars().collect::<Vec<_>>()[..] {
+        [c] => Ok([c; 3]),
+        [fast, normal, slow] => Ok([fast, normal, slow]),
+        _ => Err("expected 1 or 3 glyphs".to_string()),
+    }
+}
+
+fn parse_glyph_set(s: &str) -> Result<GlyphSet, String> {
+    let chars = s.chars().collect::<Vec<_>>();
+    if !(1..=GlyphSet::new().capacity()).contains(&chars.len()) {
+        return Err("expected 1 to 8 glyphs".to_string());
+    }
+
+    Ok(chars.into_iter().collect())
+}
+
+fn parse_scale(s: &str) -> Result<Scale, String> {
+    match s {
+        "auto" => Ok(Scale::Auto),
+        s => number_range(s, 1, 16).map(Scale::Fixed).map_err(|_| "expected auto or an integer [1-16]".to_string()),
+    }
+}
+
+fn fraction_range(s: &str) -> Result<f32, String> {
+    match s.parse::<f32>() {
+        Ok(f) if (0.1..=1.0).contains(&f) => Ok(f),
+        _ => Err("expected a number [0.1-1.0]".to_string()),
+    }
+}
+
+fn speed_range(s: &str) -> Result<f32, String> {
+    match s.parse::<f32>() {
+        Ok(f) if (0.1..=8.0).contains(&f) => Ok(f),
+        _ => Err("expected a number [0.1-8.0]".to_string()),
+    }
+}
+
+fn minutes_range(s: &str) -> Result<u16, String> {
+    number_range(s, 1, 1440)
+}
+
+fn interval_range(s: &str) -> Result<u16, String> {
+    number_range(s, 1, 100)
+}
@@ -0,0 +1,493 @@
+use std::{
+    env,
+    ffi::OsString,
+    fmt::Display,
+    ops::Range,
+    path::{Path, PathBuf},
+};
+
+use anyhow::{anyhow, bail, Context, Result};
+use clap::{
+    error::ErrorKind, parser::ValueSource, Arg, ArgAction, ArgMatches, Command, CommandFactory, FromArgMatches, ValueEnum,
+};
+use toml_edit::{ImDocument, Item, Table, Value};
+
+use crate::{
+    app::Action,
+    cli::Args,
+    keymap::{Chord, Keymap},
+    state::Mode,
+    theme::{StylePatch, Theme},
+    widget::{timer::font::Font, weather::WeatherStyles},
+};
+
+/// options which make no sense in config file
+const CLI_ONLY: [&str; 4] = ["config", "profile", "help", "version"];
+
+type Options = Vec<ConfigOption>;
+
+/// long name of option, its arguments and the line of its key
+#[derive(Clone)]
+struct ConfigOption {
+    long: String,
+    values: Vec<OsString>,
+    line: usize,
+}
+
+/// parse command line, then fill options not given in it from the profile and the rest of config file.
+/// `Args::config` is set to the loaded file and styles come from the theme and config. nothing exits here,
+/// errors of command line are returned as `clap::Error` so a reload keeps running
+pub fn load_args() -> Result<Args> {
+    let cli = env::args_os().collect::<Vec<_>>();
+    // requires and conflicts are checked once options of config are merged
+    let (matches, cli_error) = match Args::command().try_get_matches_from(&cli) {
+        Ok(matches) => (matches, None),
+        Err(e) if matches!(e.kind(), ErrorKind::MissingRequiredArgument | ErrorKind::ArgumentConflict) => {
+            let matches = Args::command().ignore_errors(true).try_get_matches_from(&cli)?;
+            (matches, Some(e))
+        }
+        Err(e) => return Err(e.into()),
+    };
+    let profile = matches.get_one::<String>("profile");
+
+    let path = match matches.get_one::<PathBuf>("config") {
+        Some(path) => Some(path.clone()),
+        None => default_path().filter(|path| path.exists()),
+    };
+    if path.is_none() && profile.is_some() {
+        bail!("--profile needs a config file, none found at {}", DefaultPath);
+    }
+
+    let config = path.as_deref().map(Config::load).transpose()?;
+    let mut argv = vec![cli[0].clone()];
+    let mut options = vec![];
+    if let (Some(config), Some(path)) = (&config, &path) {
+        options = config
+            .merged(profile.map(String::as_str))
+            .with_context(|| format!("invalid profile in config `{}`", path.display()))?;
+        options.retain(|option| !Config::overridden(option, &matches));
+        argv.extend(options.iter().flat_map(Config::option_argv));
+    }
+    argv.extend(cli.into_iter().skip(1));
+
+    let merged = Args::command()
+        .try_get_matches_from(argv)
+        .and_then(|matches| Ok((Args::from_arg_matches(&matches)?, matches)));
+    let (mut args, matches) = match (merged, &path, cli_error) {
+        (Ok(merged), _, _) => merged,
+        // the command line is wrong by itself, e.g. a required option is given nowhere
+        (Err(e), _, Some(cli_error)) if e.kind() == cli_error.kind() => return Err(cli_error.into()),
+        (Err(e), Some(path), _) => match Config::requiring(&options, &e) {
+            Some(option) => bail!("{}:{}: {}", path.display(), option.line, clap_message(&e)),
+            None => bail!("{}: {}", path.display(), clap_message(&e)),
+        },
+        (Err(e), None, _) => return Err(e.into()),
+    };
+
+    let theme = Theme::load(&args.theme)?;
+    if matches.value_source("timer_color") == Some(ValueSource::DefaultValue) {
+        args.timer_color = theme.timer_color.unwrap_or(args.timer_color);
+    }
+    args.styles = theme.styles;
+    args.timer_glyphs = theme.timer_glyphs;
+    if let Some(config) = &config {
+        config.apply_styles(&mut args.styles);
+        config.apply_keys(&mut args.keys);
+    }
+
+    args.config = path;
+    Ok(args)
+}
+
+/// `$XDG_CONFIG_HOME/tenki`, or `~/.config/tenki`
+pub fn config_dir() -> Option<PathBuf> {
+    let config_home = env::var_os("XDG_CONFIG_HOME")
+        .filter(|dir| !dir.is_empty())
+        .map(PathBuf::from)
+        .or_else(|| env::var_os("HOME").map(|home| Path::new(&home).join(".config")))?;
+
+    Some(config_home.join("tenki"))
+}
+
+pub fn default_path() -> Option<PathBuf> {
+    config_dir().map(|dir| dir.join("config.toml"))
+}
+
+/// options of config file as command line arguments, with styles the command line can not express
+pub struct Config {
+    options: Options,
+    /// `[profile.<name>]` tables overriding options
+    profiles: Vec<(String, Options)>,
+    /// `[style.<mode>]` tables overriding the theme
+    styles: Vec<(Mode, StylePatch)>,
+    /// `[keys]` table replacing keys of actions
+    keys: Vec<(Action, Vec<Chord>)>,
+}
+
+impl Config {
+    pub fn load(path: &Path) -> Result<Self> {
+        let text = std::fs::read_to_string(path).with_context(|| format!("can not read config `{}`", path.display()))?;
+        Self::parse(&text).map_err(|e| anyhow!("{}:{e}", path.display()))
+    }
+
+    /// errors are prefixed with the line
+    fn parse(text: &str) -> Result<Self, LineError> {
+        let doc = ImDocument::parse(text).map_err(|e| LineError::new(text, e.span(), e.message()))?;
+        let mut config = Self {
+            options: vec![],
+            profiles: vec![],
+            styles: vec![],
+            keys: vec![],
+        };
+
+        for (key, item) in doc.iter() {
+            let span = doc.key(key).and_then(|key| key.span()).or(item.span());
+            let err = |message: String| LineError::new(text, span.clone(), &message);
+
+            match key {
+                "style" => {
+                    let styles = item.as_table().ok_or_else(|| err("`style` must be a table".to_string()))?;
+                    config.parse_styles(text, styles)?;
+                }
+                "keys" => {
+                    let keys = item.as_table().ok_or_else(|| err("`keys` must be a table".to_string()))?;
+                    config.parse_keys(text, keys)?;
+                }
+                "profile" => {
+                    let profiles = item.as_table().ok_or_else(|| err("`profile` must be a table".to_string()))?;
+                    config.parse_profiles(text, profiles)?;
+                }
+                _ => config.options.push(Self::parse_option(text, &doc, key, item)?),
+            }
+        }
+
+        Ok(config)
+    }
+
+    fn parse_option(text: &str, table: &Table, key: &str, item: &Item) -> Result<ConfigOption, LineError> {
+        let span = table.key(key).and_then(|key| key.span()).or(item.span());
+        let err = |message: String| LineError::new(text, span.clone(), &message);
+
+        let command = Args::command();
+        let arg = command
+            .get_arguments()
+            .find(|arg| arg.get_long() == Some(key) && !CLI_ONLY.contains(&key))
+            .ok_or_else(|| err(format!("unknown key `{key}`")))?;
+        let values = Self::arg_values(arg, item).map_err(err)?;
+        Self::validate(arg, &values).map_err(err)?;
+        Self::check_loadable(key, &values).map_err(err)?;
+
+        Ok(ConfigOption {
+            long: key.to_string(),
+            values,
+            line: LineError::new(text, span, "").line,
+        })
+    }
+
+    /// `[profile.<name>]` tables of options
+    fn parse_profiles(&mut self, text: &str, profiles: &Table) -> Result<(), LineError> {
+        for (name, item) in profiles.iter() {
+            let table = item.as_table().ok_or_else(|| {
+                let span = profiles.key(name).and_then(|key| key.span());
+                LineError::new(text, span, &format!("`profile.{name}` must be a table"))
+            })?;
+
+            let options = table
+                .iter()
+                .map(|(key, item)| Self::parse_option(text, table, key, item))
+                .collect::<Result<_, _>>()?;
+            self.profiles.push((name.to_string(), options));
+        }
+
+        Ok(())
+    }
+
+    /// options of config file overridden by the profile
+    fn merged(&self, profile: Option<&str>) -> Result<Options> {
+        let mut options = self.options.clone();
+        let Some(profile) = profile else {
+            return Ok(options);
+        };
+
+        let (_, overrides) = self.profiles.iter().find(|(name, _)| name == profile).ok_or_else(|| {
+            let names = self.profiles.iter().map(|(name, _)| name.as_str()).collect::<Vec<_>>();
+            anyhow!("unknown profile `{profile}`, profiles are [{}]", names.join(", "))
+        })?;
+
+        for option in overrides {
+            match options.iter_mut().find(|o| o.long == option.long) {
+                Some(o) => *o = option.clone(),
+                None => options.push(option.clone()),
+            }
+        }
+
+        Ok(options)
+    }
+
+    /// arguments following `--<long>` for a value of config
+    fn arg_values(arg: &Arg, item: &Item) -> Result<Vec<OsString>, String> {
+        let long = arg.get_long().unwrap_or_default();
+        let value = item.as_value().ok_or_else(|| format!("`{long}` must be a value"))?;
+
+        match (arg.get_action(), value) {
+            (ArgAction::SetTrue, Value::Boolean(b)) => Ok(if *b.value() { vec!["true".into()] } else { vec![] }),
+            (ArgAction::SetTrue, _) => Err(format!("`{long}` must be true or false")),
+            (ArgAction::Append, Value::Array(array)) => array.iter().map(Self::scalar).collect(),
+            (_, Value::Array(_)) => Err(format!("`{long}` takes a single value")),
+            (_, value) => Ok(vec![Self::scalar(value)?]),
+        }
+    }
+
+    fn scalar(value: &Value) -> Result<OsString, String> {
+        let s = match value {
+            Value::String(s) => s.value().clone(),
+            Value::Integer(i) => i.value().to_string(),
+            Value::Float(f) => f.value().to_string(),
+            Value::Boolean(b) => b.value().to_string(),
+            _ => return Err("expected a string, number or boolean".to_string()),
+        };
+
+        Ok(s.into())
+    }
+
+    /// run the value parser of the option, options required together are checked later
+    fn validate(arg: &Arg, values: &[OsString]) -> Result<(), String> {
+        match Self::parse_alone(arg, values) {
+            Err(e) if e.kind() != ErrorKind::MissingRequiredArgument => Err(clap_message(&e)),
+            _ => Ok(()),
+        }
+    }
+
+    fn parse_alone(arg: &Arg, values: &[OsString]) -> Result<ArgMatches, clap::Error> {
+        let long = format!("--{}", arg.get_long().unwrap_or_default());
+        let argv = Self::to_argv(&long, arg, values);
+        Args::command().try_get_matches_from(std::iter::once(OsString::from("tenki")).chain(argv))
+    }
+
+    /// fonts and themes are loaded after merging, they are loaded here too to point at the line
+    fn check_loadable(long: &str, values: &[OsString]) -> Result<(), String> {
+        let Some(value) = values.first().and_then(|value| value.to_str()) else {
+            return Ok(());
+        };
+
+        let loaded = match long {
+            "font" => Font::load(value).map(drop),
+            "theme" => Theme::load(value).map(drop),
+            _ => Ok(()),
+        };
+        loaded.map_err(|e| format!("{e:#}"))
+    }
+
+    /// the first option requiring others when they are missing
+    fn requiring<'a>(options: &'a Options, e: &clap::Error) -> Option<&'a ConfigOption> {
+        if e.kind() != ErrorKind::MissingRequiredArgument {
+            return None;
+        }
+
+        let command = Args::command();
+        options.iter().find(|option| {
+            command
+                .get_arguments()
+                .find(|arg| arg.get_long() == Some(&option.long))
+                .is_some_and(|arg| {
+                    Self::parse_alone(arg, &option.values).is_err_and(|e| e.kind() == ErrorKind::MissingRequiredArgument)
+                })
+        })
+    }
+
+    fn to_argv(long: &str, arg: &Arg, values: &[OsString]) -> Vec<OsString> {
+        if arg.get_action().takes_values() {
+            values.iter().flat_map(|value| [long.into(), value.clone()]).collect()
+        } else {
+            values.iter().map(|_| long.into()).collect()
+        }
+    }
+
+    /// `[style.<mode>]` tables, see `StylePatch::parse` for keys
+    fn parse_styles(&mut self, text: &str, styles: &Table) -> Result<(), LineError> {
+        for (name, item) in styles.iter() {
+            let span = styles.key(name).and_then(|key| key.span());
+            let err = |message: String| LineError::new(text, span.clone(), &message);
+
+            let mode = Mode::from_str(name, false)
+                .ok()
+                .filter(|mode| !matches!(mode, Mode::Disable))
+                .ok_or_else(|| err(format!("unknown mode `{name}` of style, expected rain, snow or meteor")))?;
+            let table = item.as_table().ok_or_else(|| err(format!("`style.{name}` must be a table")))?;
+            self.styles.push((mode, StylePatch::parse(text, table)?));
+        }
+
+        Ok(())
+    }
+
+    fn apply_styles(&self, styles: &mut WeatherStyles) {
+        for (mode, patch) in &self.styles {
+            if let Some(style) = styles.get_mut(*mode) {
+                patch.apply(style);
+            }
+        }
+    }
+
+    /// `[keys]` table of actions to a key or an array of keys
+    fn parse_keys(&mut self, text: &str, keys: &Table) -> Result<(), LineError> {
+        for (name, item) in keys.iter() {
+            let span = keys.key(name).and_then(|key| key.span()).or(item.span());
+            let err = |message: String| LineError::new(text, span.clone(), &message);
+
+            let action = Action::from_str(name, false).map_err(|_| {
+                let actions = Action::value_variants()
+                    .iter()
+                    .filter_map(|action| Some(action.to_possible_value()?.get_name().to_string()))
+                    .collect::<Vec<_>>();
+                err(format!("unknown action `{name}`, expected one of [{}]", actions.join(", ")))
+            })?;
+
+            let parse = |value: &Value| {
+                let key = value.as_str().ok_or_else(|| err(format!("keys of `{name}` must be strings")))?;
+                key.parse::<Chord>().map_err(err)
+            };
+            let chords = match item.as_value() {
+                Some(Value::Array(array)) => array.iter().map(parse).collect::<Result<_, _>>()?,
+                Some(value) => vec![parse(value)?],
+                None => return Err(err(format!("`{name}` must be a key or an array of keys"))),
+            };
+            self.keys.push((action, chords));
+        }
+
+        Ok(())
+    }
+
+    fn apply_keys(&self, keys: &mut Keymap) {
+        for (action, chords) in &self.keys {
+            keys.bind(*action, chords);
+        }
+    }
+
+    /// the option is given in command line or conflicts with it
+    fn overridden(option: &ConfigOption, matches: &ArgMatches) -> bool {
+        let command = Args::command();
+        let Some(arg) = command.get_arguments().find(|arg| arg.get_long() == Some(&option.long)) else {
+            return true;
+        };
+
+        let from_cli = |arg: &Arg| matches.value_source(arg.get_id().as_str()) == Some(ValueSource::CommandLine);
+        let overridden = command.get_arguments().any(|other| from_cli(other) && Self::conflicts(&command, arg, other));
+        overridden
+    }
+
+    /// arguments of the option as given in command line
+    fn option_argv(option: &ConfigOption) -> Vec<OsString> {
+        let command = Args::command();
+        let arg = command.get_arguments().find(|arg| arg.get_long() == Some(&option.long));
+        arg.map_or(vec![], |arg| Self::to_argv(&format!("--{}", option.long), arg, &option.values))
+    }
+
+    /// the same arg or conflicting args, a conflict may be declared on either side
+    fn conflicts(command: &Command, arg: &Arg, other: &Arg) -> bool {
+        let declared = |a: &Arg, b: &Arg| command.get_arg_conflicts_with(a).iter().any(|c| c.get_id() == b.get_id());
+        arg.get_id() == other.get_id() || declared(arg, other) || declared(other, arg)
+    }
+}
+
+/// default path of config file in messages
+struct DefaultPath;
+
+impl Display for DefaultPath {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match default_path() {
+            Some(path) => path.display().fmt(f),
+            None => "$XDG_CONFIG_HOME/tenki/config.toml".fmt(f),
+        }
+    }
+}
+
+/// clap error without the `error: ` prefix and the footer of usage and help
+fn clap_message(e: &clap::Error) -> String {
+    let message = e.to_string();
+    let body = message
+        .lines()
+        .take_while(|line| !line.starts_with("Usage:") && !line.starts_with("For more information"))
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    let body = body.trim();
+    body.strip_prefix("error: ").unwrap_or(body).to_string()
+}
+
+/// error at a line of config or theme file
+#[derive(Debug)]
+pub struct LineError {
+    line: usize,
+    message: String,
+}
+
+impl LineError {
+    pub fn new(text: &str, span: Option<Range<usize>>, message: &str) -> Self {
+        let start = span.map_or(0, |span| span.start.min(text.len()));
+        Self {
+            line: text[..start].matches('\n').count() + 1,
+            message: message.trim().to_string(),
+        }
+    }
+}
+
+impl Display for LineError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: {}", self.line, self.message)
+    }
+}
+
+impl std::error::Error for LineError {}
+
+#[cfg(test)]
+mod tests {
+    use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+    use super::*;
+
+    /// keymap of `[keys]` table over default keys
+    fn keymap(keys: &str) -> Result<Keymap, String> {
+        let config = Config::parse(&format!("fps = 30\n[keys]\n{keys}")).map_err(|e| e.to_string())?;
+        let mut keymap = Keymap::default();
+        config.apply_keys(&mut keymap);
+        Ok(keymap)
+    }
+
+    fn actions(keymap: &Keymap, code: KeyCode) -> Vec<Action> {
+        keymap.actions(KeyEvent::new(code, KeyModifiers::NONE)).collect()
+    }
+
+    #[test]
+    fn keys_replace_chords_of_actions() {
+        let keymap = keymap("quit = [\"x\", \"ctrl-c\"]\nnext-mode = \"shift-m\"\nlap = \"up\"").unwrap();
+
+        assert_eq!(actions(&keymap, KeyCode::Char('x')), [Action::Quit]);
+        assert_eq!(actions(&keymap, KeyCode::Char('q')), []);
+        assert_eq!(actions(&keymap, KeyCode::Char('m')), []);
+        assert_eq!(actions(&keymap, KeyCode::Char('M')), [Action::NextMode]);
+        assert_eq!(actions(&keymap, KeyCode::Up), [Action::Lap]);
+        assert_eq!(actions(&keymap, KeyCode::Char('l')), []);
+    }
+
+    #[test]
+    fn keys_shared_with_timer_actions_are_taken() {
+        let keymap = keymap("pause = \"space\"\nsnooze = \"s\"").unwrap();
+
+        assert_eq!(actions(&keymap, KeyCode::Char(' ')), [Action::Pause]);
+        assert_eq!(actions(&keymap, KeyCode::Char('s')), [Action::Snooze]);
+        assert_eq!(keymap.chords(Action::Skip).count(), 1);
+    }
+
+    #[test]
+    fn keys_errors_at_their_line() {
+        assert_eq!(
+            keymap("quit = \"q\"\njump = \"j\"").unwrap_err(),
+            "4: unknown action `jump`, expected one of [quit, next-mode, level-up, level-down, next-wind, toggle-fps, \
+             toggle-blink, next-timer-mode, pause, step, speed-up, speed-down, help, screenshot, start-stop, lap, reset, skip, \
+             dismiss, snooze]"
+        );
+        assert_eq!(keymap("help = \"hyper-h\"").unwrap_err(), "3: unknown key `hyper-h`");
+        assert_eq!(keymap("help = [\"?\", 1]").unwrap_err(), "3: keys of `help` must be strings");
+        assert_eq!(keymap("[keys.help]\nkey = \"?\"").unwrap_err(), "3: `help` must be a key or an array of keys");
+    }
+}
@@ -0,0 +1,242 @@
+use std::{fmt::Display, str::FromStr};
+
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+use crate::app::Action;
+
+/// keys of actions when not given by config
+const DEFAULT_KEYS: [(Action, &[&str]); 20] = [
+    (Action::Quit, &["q", "shift-q", "esc", "ctrl-c"]),
+    (Action::NextMode, &["m", "shift-m"]),
+    (Action::LevelUp, &["+", "="]),
+    (Action::LevelDown, &["-"]),
+    (Action::NextWind, &["w", "shift-w"]),
+    (Action::ToggleFps, &["f", "shift-f"]),
+    (Action::ToggleBlink, &["b", "shift-b"]),
+    (Action::NextTimerMode, &["t", "shift-t"]),
+    (Action::Pause, &["space", "p", "shift-p"]),
+    (Action::Step, &["."]),
+    (Action::SpeedUp, &["]"]),
+    (Action::SpeedDown, &["["]),
+    (Action::Help, &["?"]),
+    (Action::Screenshot, &["ctrl-s"]),
+    (Action::StartStop, &["space"]),
+    (Action::Lap, &["l", "shift-l"]),
+    (Action::Reset, &["r", "shift-r"]),
+    (Action::Skip, &["s", "shift-s"]),
+    (Action::Dismiss, &["enter", "d", "shift-d"]),
+    (Action::Snooze, &["z", "shift-z"]),
+];
+
+/// keys named in config other than chars and function keys
+const KEY_NAMES: [(&str, KeyCode); 13] = [
+    ("esc", KeyCode::Esc),
+    ("enter", KeyCode::Enter),
+    ("space", KeyCode::Char(' ')),
+    ("tab", KeyCode::Tab),
+    ("backspace", KeyCode::Backspace),
+    ("up", KeyCode::Up),
+    ("down", KeyCode::Down),
+    ("left", KeyCode::Left),
+    ("right", KeyCode::Right),
+    ("home", KeyCode::Home),
+    ("end", KeyCode::End),
+    ("pageup", KeyCode::PageUp),
+    ("pagedown", KeyCode::PageDown),
+];
+
+/// a key with modifiers, shift of letters is kept in their case
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Chord {
+    code: KeyCode,
+    modifiers: KeyModifiers,
+}
+
+impl Chord {
+    fn new(code: KeyCode, modifiers: KeyModifiers) -> Self {
+        match code {
+            // terminals differ in reporting shift of symbols, it is implied by the char
+            KeyCode::Char(c) => Self {
+                code: KeyCode::Char(c),
+                modifiers: modifiers.difference(KeyModifiers::SHIFT),
+            },
+            code => Self { code, modifiers },
+        }
+    }
+
+    fn parse_code(s: &str) -> Option<KeyCode> {
+        let mut chars = s.chars();
+        if let (Some(c), None) = (chars.next(), chars.next()) {
+            return Some(KeyCode::Char(c));
+        }
+
+        match KEY_NAMES.iter().find(|(name, _)| *name == s) {
+            Some((_, code)) => Some(*code),
+            None => Some(KeyCode::F(s.strip_prefix('f')?.parse().ok().filter(|n| (1..=12).contains(n))?)),
+        }
+    }
+}
+
+impl From<KeyEvent> for Chord {
+    fn from(key: KeyEvent) -> Self {
+        Self::new(key.code, key.modifiers)
+    }
+}
+
+/// `ctrl-`, `alt-` and `shift-` followed by a char or a key name like `esc`, `space` or `f1`
+impl FromStr for Chord {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let err = || format!("unknown key `{s}`");
+        let mut rest = s;
+        let mut modifiers = KeyModifiers::NONE;
+
+        // `-` alone or after modifiers is the minus key
+        while let Some((modifier, key)) = rest.split_once('-').filter(|(modifier, _)| !modifier.is_empty()) {
+            modifiers |= match modifier {
+                "ctrl" => KeyModifiers::CONTROL,
+                "alt" => KeyModifiers::ALT,
+                "shift" => KeyModifiers::SHIFT,
+                _ => return Err(err()),
+            };
+            rest = key;
+        }
+
+        let code = match Self::parse_code(rest).ok_or_else(err)? {
+            KeyCode::Char(c) if modifiers.contains(KeyModifiers::SHIFT) => KeyCode::Char(c.to_ascii_uppercase()),
+            code => code,
+        };
+
+        Ok(Self::new(code, modifiers))
+    }
+}
+
+impl Display for Chord {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (modifier, name) in [(KeyModifiers::CONTROL, "ctrl-"), (KeyModifiers::ALT, "alt-"), (KeyModifiers::SHIFT, "shift-")] {
+            if self.modifiers.contains(modifier) {
+                name.fmt(f)?;
+            }
+        }
+
+        match (KEY_NAMES.iter().find(|(_, code)| *code == self.code), self.code) {
+            (Some((name, _)), _) => name.fmt(f),
+            (None, KeyCode::Char(c)) => c.fmt(f),
+            (None, KeyCode::F(n)) => write!(f, "f{n}"),
+            (None, _) => "?".fmt(f),
+        }
+    }
+}
+
+/// chords of actions
+#[derive(Clone, Debug)]
+pub struct Keymap(Vec<(Chord, Action)>);
+
+impl Default for Keymap {
+    fn default() -> Self {
+        let keys = DEFAULT_KEYS
+            .iter()
+            .flat_map(|(action, chords)| chords.iter().map(|chord| (Chord::from_str(chord).unwrap(), *action)))
+            .collect();
+
+        Self(keys)
+    }
+}
+
+impl Keymap {
+    /// actions of the key, a key may be shared by actions of different modes
+    pub fn actions(&self, key: KeyEvent) -> impl Iterator<Item = Action> + '_ {
+        let chord = Chord::from(key);
+        self.0.iter().filter(move |(c, _)| *c == chord).map(|(_, action)| *action)
+    }
+
+    pub fn chords(&self, action: Action) -> impl Iterator<Item = Chord> + '_ {
+        self.0.iter().filter(move |(_, a)| *a == action).map(|(chord, _)| *chord)
+    }
+
+    /// replace chords of the action, a chord is taken from other actions
+    pub fn bind(&mut self, action: Action, chords: &[Chord]) {
+        self.0.retain(|(c, a)| *a != action && !chords.contains(c));
+        self.0.extend(chords.iter().map(|chord| (*chord, action)));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn chord(s: &str) -> Chord {
+        s.parse().unwrap()
+    }
+
+    fn key(code: KeyCode, modifiers: KeyModifiers) -> KeyEvent {
+        KeyEvent::new(code, modifiers)
+    }
+
+    #[test]
+    fn minus_alone_and_after_modifiers() {
+        assert_eq!(chord("-"), Chord::new(KeyCode::Char('-'), KeyModifiers::NONE));
+        assert_eq!(chord("ctrl--"), Chord::new(KeyCode::Char('-'), KeyModifiers::CONTROL));
+        assert_eq!(chord("ctrl-alt--"), Chord::new(KeyCode::Char('-'), KeyModifiers::CONTROL | KeyModifiers::ALT));
+    }
+
+    #[test]
+    fn shift_of_letter_is_uppercase() {
+        assert_eq!(chord("shift-m"), chord("M"));
+        assert_ne!(chord("shift-m"), chord("m"));
+
+        // terminals report uppercase letters with or without shift
+        assert_eq!(Chord::from(key(KeyCode::Char('M'), KeyModifiers::SHIFT)), chord("shift-m"));
+        assert_eq!(Chord::from(key(KeyCode::Char('M'), KeyModifiers::NONE)), chord("shift-m"));
+    }
+
+    #[test]
+    fn ctrl_shift_letter() {
+        let ctrl_shift_m = chord("ctrl-shift-m");
+
+        assert_eq!(ctrl_shift_m, Chord::new(KeyCode::Char('M'), KeyModifiers::CONTROL));
+        assert_eq!(Chord::from(key(KeyCode::Char('M'), KeyModifiers::CONTROL | KeyModifiers::SHIFT)), ctrl_shift_m);
+        assert_ne!(ctrl_shift_m, chord("ctrl-m"));
+    }
+
+    #[test]
+    fn shift_of_named_key_is_kept() {
+        assert_eq!(chord("shift-tab"), Chord::new(KeyCode::Tab, KeyModifiers::SHIFT));
+        assert_eq!(chord("f12"), Chord::new(KeyCode::F(12), KeyModifiers::NONE));
+    }
+
+    #[test]
+    fn unknown_keys() {
+        for s in ["", "ctrl-", "hyper-x", "ctrl-foo", "f0", "f13", "escape"] {
+            assert_eq!(s.parse::<Chord>(), Err(format!("unknown key `{s}`")));
+        }
+    }
+
+    #[test]
+    fn default_chords_display_as_parsed() {
+        let keys = Keymap::default();
+        for (chord, _) in &keys.0 {
+            assert_eq!(&chord.to_string().parse::<Chord>().unwrap(), chord);
+        }
+    }
+
+    #[test]
+    fn space_is_shared_by_pause_and_start_stop() {
+        let keys = Keymap::default();
+        let space = key(KeyCode::Char(' '), KeyModifiers::NONE);
+
+        assert_eq!(keys.actions(space).collect::<Vec<_>>(), [Action::Pause, Action::StartStop]);
+    }
+
+    #[test]
+    fn bind_takes_chords_from_other_actions() {
+        let mut keys = Keymap::default();
+        keys.bind(Action::Lap, &[chord("p"), chord("space")]);
+
+        assert_eq!(keys.actions(key(KeyCode::Char('p'), KeyModifiers::NONE)).collect::<Vec<_>>(), [Action::Lap]);
+        assert_eq!(keys.actions(key(KeyCode::Char(' '), KeyModifiers::NONE)).collect::<Vec<_>>(), [Action::Lap]);
+        assert_eq!(keys.actions(key(KeyCode::Char('l'), KeyModifiers::NONE)).count(), 0);
+        assert_eq!(keys.chords(Action::Pause).collect::<Vec<_>>(), [chord("shift-p")]);
+    }
+}
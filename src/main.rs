@@ -1,6 +1,9 @@
 mod app;
 mod cli;
+mod config;
+mod keymap;
 mod state;
+mod theme;
 mod tui;
 mod ui;
 mod util;
@@ -9,14 +12,17 @@ mod widget;
 
 use anyhow::Result;
 use app::App;
-use clap::Parser;
-use cli::Args;
 use weather::Weather;
 
 #[tokio::main]
 async fn main() -> Result<()> {
-    let args = Args::parse();
-    let mut app = App::new(args, Weather::from(args))?;
+    // errors of command line are printed by clap with usage
+    let args = config::load_args().map_err(|e| match e.downcast::<clap::Error>() {
+        Ok(e) => e.exit(),
+        Err(e) => e,
+    })?;
+    let weather = Weather::from(&args);
+    let mut app = App::new(args, weather)?;
     app.run().await?;
     Ok(())
 }
@@ -0,0 +1,212 @@
+use chrono::{Duration, NaiveDateTime, NaiveTime};
+
+use crate::app::Action;
+
+use super::{
+    buffer::RenderBuffer,
+    clock::{Clock, LocalClock},
+    EachFrameImpl, ShouldRender,
+};
+
+pub struct AlarmState<C: Clock = LocalClock> {
+    pub flash: bool,
+    clock: C,
+    alarms: Vec<NaiveTime>,
+    snooze: Duration,
+    snoozed_until: Option<NaiveDateTime>,
+    last_check: NaiveDateTime,
+    ringing: bool,
+}
+
+impl AlarmState {
+    pub fn new(alarms: Vec<NaiveTime>, snooze_minutes: u16) -> Self {
+        Self::with_clock(alarms, snooze_minutes, LocalClock)
+    }
+}
+
+impl<C: Clock> AlarmState<C> {
+    pub fn with_clock(alarms: Vec<NaiveTime>, snooze_minutes: u16, clock: C) -> Self {
+        Self {
+            flash: false,
+            last_check: clock.now(),
+            snooze: Duration::minutes(snooze_minutes as i64),
+            snoozed_until: None,
+            ringing: false,
+            alarms,
+            clock,
+        }
+    }
+
+    pub fn is_ringing(&self) -> bool {
+        self.ringing
+    }
+
+    /// check alarms since last call, return true when it starts ringing
+    pub fn check(&mut self) -> bool {
+        let now = self.clock.now();
+        let last_check = std::mem::replace(&mut self.last_check, now);
+        if self.ringing {
+            return false;
+        }
+
+        let snooze_end = self.snoozed_until.is_some_and(|until| until <= now);
+        let reached = self.alarms.iter().any(|alarm| {
+            let mut at = now.date().and_time(*alarm);
+            if at > now {
+                at -= Duration::days(1);
+            }
+
+            at > last_check
+        });
+
+        if snooze_end || reached {
+            self.snoozed_until = None;
+            self.ringing = true;
+        }
+
+        self.ringing
+    }
+
+    pub fn dismiss(&mut self) {
+        self.ringing = false;
+        self.flash = false;
+    }
+
+    pub fn snooze(&mut self) {
+        self.dismiss();
+        self.snoozed_until = Some(self.clock.now() + self.snooze);
+    }
+
+    /// handle dismiss and snooze, return true when the alarm stopped ringing
+    pub fn handle_action(&mut self, action: Action) -> bool {
+        match action {
+            Action::Dismiss => self.dismiss(),
+            Action::Snooze => self.snooze(),
+            _ => return false,
+        }
+
+        true
+    }
+}
+
+impl<C: Clock> EachFrameImpl for AlarmState<C> {
+    fn on_frame(&mut self, _: &mut RenderBuffer, _: u64, frame: u64) -> ShouldRender {
+        if self.ringing && frame.is_multiple_of(20) {
+            self.flash = !self.flash;
+            ShouldRender::Render
+        } else {
+            ShouldRender::Skip
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{cell::Cell, rc::Rc};
+
+    use chrono::NaiveDate;
+
+    use super::*;
+
+    /// clock moved by the test
+    #[derive(Clone)]
+    struct FakeClock(Rc<Cell<NaiveDateTime>>);
+
+    impl FakeClock {
+        fn at(h: u32, m: u32, s: u32) -> Self {
+            Self(Rc::new(Cell::new(time(h, m, s))))
+        }
+
+        fn set(&self, h: u32, m: u32, s: u32) {
+            self.0.set(time(h, m, s));
+        }
+    }
+
+    impl Clock for FakeClock {
+        fn now(&self) -> NaiveDateTime {
+            self.0.get()
+        }
+    }
+
+    fn time(h: u32, m: u32, s: u32) -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(2024, 1, 1).unwrap().and_hms_opt(h, m, s).unwrap()
+    }
+
+    fn alarm_at_seven(clock: &FakeClock) -> AlarmState<FakeClock> {
+        AlarmState::with_clock(vec![NaiveTime::from_hms_opt(7, 0, 0).unwrap()], 5, clock.clone())
+    }
+
+    #[test]
+    fn fires_at_set_time() {
+        let clock = FakeClock::at(6, 59, 0);
+        let mut alarm = alarm_at_seven(&clock);
+
+        clock.set(6, 59, 59);
+        assert!(!alarm.check());
+
+        clock.set(7, 0, 0);
+        assert!(alarm.check());
+        assert!(alarm.is_ringing());
+    }
+
+    #[test]
+    fn does_not_refire_within_minute() {
+        let clock = FakeClock::at(6, 59, 59);
+        let mut alarm = alarm_at_seven(&clock);
+
+        clock.set(7, 0, 0);
+        assert!(alarm.check());
+        assert!(alarm.handle_action(Action::Dismiss));
+
+        clock.set(7, 0, 30);
+        assert!(!alarm.check());
+        clock.set(7, 0, 59);
+        assert!(!alarm.check());
+        assert!(!alarm.is_ringing());
+    }
+
+    #[test]
+    fn snooze_rings_again_after_snooze_minutes() {
+        let clock = FakeClock::at(6, 59, 59);
+        let mut alarm = alarm_at_seven(&clock);
+
+        clock.set(7, 0, 0);
+        assert!(alarm.check());
+        assert!(alarm.handle_action(Action::Snooze));
+        assert!(!alarm.is_ringing());
+
+        clock.set(7, 4, 59);
+        assert!(!alarm.check());
+
+        clock.set(7, 5, 0);
+        assert!(alarm.check());
+    }
+
+    #[test]
+    fn dismiss_stops_until_next_day() {
+        let clock = FakeClock::at(6, 59, 59);
+        let mut alarm = alarm_at_seven(&clock);
+
+        clock.set(7, 0, 0);
+        assert!(alarm.check());
+        assert!(alarm.handle_action(Action::Dismiss));
+        assert!(!alarm.is_ringing());
+        assert!(!alarm.flash);
+
+        clock.set(7, 10, 0);
+        assert!(!alarm.check());
+        clock.set(23, 59, 59);
+        assert!(!alarm.check());
+    }
+
+    #[test]
+    fn other_keys_keep_ringing() {
+        let clock = FakeClock::at(6, 59, 59);
+        let mut alarm = alarm_at_seven(&clock);
+
+        clock.set(7, 0, 0);
+        assert!(alarm.check());
+        assert!(!alarm.handle_action(Action::Pause));
+        assert!(alarm.is_ringing());
+    }
+}
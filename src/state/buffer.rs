@@ -5,11 +5,11 @@ use ratatui::layout::Size;
 use tinyvec::ArrayVec;
 
 use super::Column;
-use super::CellType;
+use super::Particle;
 
 pub struct RenderBuffer {
     pub buf: Vec<Column>,
-    pub line: Vec<CellType>,
+    pub line: Vec<Particle>,
 }
 
 impl RenderBuffer {
@@ -18,7 +18,7 @@ impl RenderBuffer {
         for _ in 0..size.width {
             let mut column = Vec::with_capacity(size.height as usize);
             for _ in 0..size.height {
-                column.push(ArrayVec::<[CellType; 3]>::default());
+                column.push(ArrayVec::<[Particle; 3]>::default());
             }
 
             buf.push(Rc::new(RefCell::new(column)));
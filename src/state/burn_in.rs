@@ -0,0 +1,81 @@
+use chrono::NaiveTime;
+use ratatui::style::Color;
+
+use crate::util::{from_hsv, to_hsv, to_rgb};
+
+use super::clock::{Clock, LocalClock};
+
+/// seconds between shifts of the timer
+const SHIFT_INTERVAL: u32 = 120;
+/// offsets of the timer in cells, a loop around its origin
+const SHIFTS: [(i16, i16); 9] = [(0, 0), (2, 0), (2, 1), (0, 1), (-2, 1), (-2, 0), (-2, -1), (0, -1), (2, -1)];
+/// seconds of a round of hue
+const HUE_CYCLE: u32 = 3600;
+/// saturation given to gray colors so that they cycle too
+const MIN_SATURATION: f32 = 0.25;
+
+/// start and end of night hours, the end may be on the next day
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub struct NightHours(pub NaiveTime, pub NaiveTime);
+
+impl NightHours {
+    pub fn contains(&self, time: NaiveTime) -> bool {
+        let NightHours(start, end) = *self;
+        if start <= end {
+            start <= time && time < end
+        } else {
+            time >= start || time < end
+        }
+    }
+}
+
+/// shift the timer, cycle its color and dim the screen at night for always-on displays
+pub struct BurnInState<C: Clock = LocalClock> {
+    /// screen is dimmed
+    pub night: bool,
+    clock: C,
+    night_hours: Option<NightHours>,
+    seconds: u32,
+}
+
+impl BurnInState {
+    pub fn new(night_hours: Option<NightHours>) -> Self {
+        Self::with_clock(night_hours, LocalClock)
+    }
+}
+
+impl<C: Clock> BurnInState<C> {
+    pub fn with_clock(night_hours: Option<NightHours>, clock: C) -> Self {
+        let mut state = Self {
+            night: false,
+            clock,
+            night_hours,
+            seconds: 0,
+        };
+        state.update_night();
+        state
+    }
+
+    /// called each second
+    pub fn on_second(&mut self) {
+        self.seconds = self.seconds.wrapping_add(1);
+        self.update_night();
+    }
+
+    fn update_night(&mut self) {
+        let now = self.clock.now().time();
+        self.night = self.night_hours.is_some_and(|hours| hours.contains(now));
+    }
+
+    /// offset of the timer in cells
+    pub fn shift(&self) -> (i16, i16) {
+        SHIFTS[(self.seconds / SHIFT_INTERVAL) as usize % SHIFTS.len()]
+    }
+
+    /// rotate hue of color slowly, the default color of terminal is taken as white
+    pub fn cycle_color(&self, color: Color) -> Color {
+        let (h, s, v) = to_hsv(to_rgb(color).unwrap_or((255, 255, 255)));
+        let h = h + (self.seconds % HUE_CYCLE) as f32 / HUE_CYCLE as f32 * 360.0;
+        from_hsv(h, s.max(MIN_SATURATION), v)
+    }
+}
@@ -0,0 +1,111 @@
+use std::fmt::Display;
+
+use clap::ValueEnum;
+
+use super::{buffer::RenderBuffer, timer::Timer, EachFrameImpl, ShouldRender};
+
+/// frames of timer color pulse after chiming
+const PULSE_FRAMES: u16 = 180;
+
+#[derive(Copy, Clone, PartialEq, Eq, ValueEnum)]
+pub enum ChimeInterval {
+    Hourly,
+    Half,
+    Quarter,
+}
+
+impl ChimeInterval {
+    fn minutes(&self) -> u8 {
+        match self {
+            ChimeInterval::Hourly => 60,
+            ChimeInterval::Half => 30,
+            ChimeInterval::Quarter => 15,
+        }
+    }
+}
+
+impl Display for ChimeInterval {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = match *self {
+            ChimeInterval::Hourly => "hourly",
+            ChimeInterval::Half => "half",
+            ChimeInterval::Quarter => "quarter",
+        };
+
+        s.fmt(f)
+    }
+}
+
+pub struct ChimeState {
+    pub highlight: bool,
+    interval: Option<ChimeInterval>,
+    /// ring the bell as many times as the hour on the hour
+    count_hours: bool,
+    rings: u8,
+    pulse: u16,
+    last: (u8, u8),
+}
+
+impl ChimeState {
+    pub fn new(interval: Option<ChimeInterval>, count_hours: bool, timer: Timer) -> Self {
+        Self {
+            highlight: false,
+            interval,
+            count_hours,
+            rings: 0,
+            pulse: 0,
+            last: (timer.hours, timer.minutes),
+        }
+    }
+
+    /// check wall clock each second, return true when it starts chiming
+    pub fn check(&mut self, timer: Timer) -> bool {
+        let Some(interval) = self.interval else {
+            return false;
+        };
+
+        let now = (timer.hours, timer.minutes);
+        if now == self.last {
+            return false;
+        }
+
+        self.last = now;
+        if !timer.minutes.is_multiple_of(interval.minutes()) {
+            return false;
+        }
+
+        self.rings = match timer.hours % 12 {
+            h if self.count_hours && timer.minutes == 0 => if h == 0 { 12 } else { h },
+            _ => 1,
+        };
+        self.pulse = PULSE_FRAMES;
+        true
+    }
+
+    /// take one of remaining rings, the bell rings once a second
+    pub fn take_ring(&mut self) -> bool {
+        if self.rings == 0 {
+            return false;
+        }
+
+        self.rings -= 1;
+        true
+    }
+}
+
+impl EachFrameImpl for ChimeState {
+    fn on_frame(&mut self, _: &mut RenderBuffer, _: u64, frame: u64) -> ShouldRender {
+        if self.pulse == 0 {
+            return ShouldRender::Skip;
+        }
+
+        self.pulse -= 1;
+        let highlight = self.pulse > 0 && (frame / 15).is_multiple_of(2);
+        if highlight == self.highlight {
+            return ShouldRender::Skip;
+        }
+
+        self.highlight = highlight;
+        ShouldRender::Render
+    }
+}
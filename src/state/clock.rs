@@ -0,0 +1,15 @@
+use chrono::{Local, NaiveDateTime};
+
+/// source of wall clock time, injectable for checking alarms and night hours
+pub trait Clock {
+    fn now(&self) -> NaiveDateTime;
+}
+
+#[derive(Copy, Clone, Default)]
+pub struct LocalClock;
+
+impl Clock for LocalClock {
+    fn now(&self) -> NaiveDateTime {
+        Local::now().naive_local()
+    }
+}
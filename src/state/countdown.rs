@@ -0,0 +1,44 @@
+use std::time::{Duration, Instant};
+
+pub struct Countdown {
+    deadline: Option<Instant>,
+    remaining: Duration,
+}
+
+impl Countdown {
+    /// create a running countdown
+    pub fn new(duration: Duration) -> Self {
+        Self {
+            deadline: Some(Instant::now() + duration),
+            remaining: duration,
+        }
+    }
+
+    pub fn remaining(&self) -> Duration {
+        match self.deadline {
+            Some(deadline) => deadline.saturating_duration_since(Instant::now()),
+            None => self.remaining,
+        }
+    }
+
+    /// remaining time rounded up to whole seconds, so a fresh 25 minutes countdown shows 25:00
+    pub fn remaining_secs(&self) -> u64 {
+        let remaining = self.remaining();
+        remaining.as_secs() + u64::from(remaining.subsec_nanos() > 0)
+    }
+
+    pub fn is_paused(&self) -> bool {
+        self.deadline.is_none()
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.remaining().is_zero()
+    }
+
+    pub fn toggle(&mut self) {
+        match self.deadline.take() {
+            Some(deadline) => self.remaining = deadline.saturating_duration_since(Instant::now()),
+            None => self.deadline = Some(Instant::now() + self.remaining),
+        }
+    }
+}
@@ -1,4 +1,4 @@
-use super::{buffer::RenderBuffer, Cell, CellType, Column, EachFrameImpl, Mode, ShouldRender};
+use super::{buffer::RenderBuffer, Cell, CellType, Column, EachFrameImpl, Mode, Particle, ShouldRender};
 
 pub struct DroppingState {
     pub threshold: u16,
@@ -17,11 +17,12 @@ impl DroppingState {
         for g in 0..groups {
             let range = if groups.saturating_sub(1) == g { last_group } else { GROUP_SIZE };
             for i in 0..range {
-                rb.line.push(if seed & (1 << i) != 0 {
+                let kind = if seed & (1 << i) != 0 {
                     Self::get_drop_speed(seed.saturating_sub(i), self.threshold)
                 } else {
                     CellType::None
-                });
+                };
+                rb.line.push(Particle::new(kind, Self::get_variant(seed, g * GROUP_SIZE + i)));
             }
         }
     }
@@ -57,7 +58,7 @@ impl DroppingState {
 
             'state: for i in 0..current.len() {
                 let state = match current.get(i) {
-                    Some(s) if frame.is_multiple_of(mode.get_frame_by_speed(*s)) => s,
+                    Some(s) if frame.is_multiple_of(mode.get_frame_by_speed(s.kind)) => s,
                     _ => continue 'state
                 };
 
@@ -79,8 +80,8 @@ impl DroppingState {
     }
 
     #[inline]
-    fn merge_drop_state(mut cell: Cell, state: CellType) -> Cell {
-        if !cell.contains(&state) && state != CellType::None {
+    fn merge_drop_state(mut cell: Cell, state: Particle) -> Cell {
+        if !cell.iter().any(|c| c.kind == state.kind) && state.kind != CellType::None {
             let _ = cell.try_push(state);
         };
 
@@ -88,8 +89,14 @@ impl DroppingState {
     }
 
     #[inline]
-    fn remove_drop_state(cell: Cell, state: CellType) -> Cell {
-        cell.into_iter().filter(|c| *c != state).collect()
+    fn remove_drop_state(cell: Cell, state: Particle) -> Cell {
+        cell.into_iter().filter(|c| c.kind != state.kind).collect()
+    }
+
+    /// glyph variant of a new drop at index of line, spread over the bits of seed
+    #[inline]
+    fn get_variant(seed: u64, index: u64) -> u8 {
+        (seed.rotate_left(index as u32).wrapping_mul(0x9e37_79b9_7f4a_7c15) >> 56) as u8
     }
 
     #[inline]
@@ -5,18 +5,31 @@ use rand::{rngs::SmallRng, RngCore, SeedableRng};
 use ratatui::layout::{Rect, Size};
 use tinyvec::ArrayVec;
 
+use crate::{app::Action, cli::Args, widget::timer::timer_face};
+
 use self::{
+    alarm::AlarmState,
     buffer::RenderBuffer,
-    timer::{Timer, TimerState},
+    burn_in::BurnInState,
+    chime::ChimeState,
+    pomodoro::{PomodoroConfig, Transition},
+    timer::{Timer, TimerSource, TimerState},
 };
 
+pub mod alarm;
 pub mod buffer;
+pub mod burn_in;
+pub mod chime;
+pub mod clock;
+pub mod countdown;
 pub mod dropping;
+pub mod pomodoro;
+pub mod stopwatch;
 pub mod tail;
 pub mod timer;
 pub mod wind;
 
-pub type Cell = ArrayVec<[CellType; 3]>;
+pub type Cell = ArrayVec<[Particle; 3]>;
 pub type Column = Rc<RefCell<Vec<Cell>>>;
 
 pub trait EachFrameImpl {
@@ -101,7 +114,7 @@ impl Direction {
     }
 }
 
-#[derive(Copy, Clone)]
+#[derive(Copy, Clone, PartialEq)]
 pub struct Position(u16, u16);
 impl From<Rect> for Position {
     fn from(value: Rect) -> Self {
@@ -152,6 +165,20 @@ impl CellType {
     }
 }
 
+/// a drop, flake or tail in a cell
+#[derive(Copy, Clone, PartialEq, Eq, Default)]
+pub struct Particle {
+    pub kind: CellType,
+    /// picks a glyph of the glyph set, kept while the particle moves
+    pub variant: u8,
+}
+
+impl Particle {
+    pub fn new(kind: CellType, variant: u8) -> Self {
+        Self { kind, variant }
+    }
+}
+
 #[derive(Copy, Clone, Default, ValueEnum, PartialEq, Eq)]
 pub enum Mode {
     #[default]
@@ -179,6 +206,24 @@ impl Display for Mode {
 }
 
 impl Mode {
+    /// mode switched to by key
+    pub fn next(&self) -> Self {
+        match self {
+            Mode::Rain => Mode::Snow,
+            Mode::Snow => Mode::Meteor,
+            Mode::Meteor => Mode::Disable,
+            Mode::Disable => Mode::Rain,
+        }
+    }
+
+    /// effect level when not given by args
+    pub fn default_level(&self) -> u16 {
+        match self {
+            Mode::Meteor => 500,
+            _ => 50,
+        }
+    }
+
     pub fn get_frame_by_speed(&self, s: CellType) -> u64 {
         use CellType::*;
         use Mode::*;
@@ -206,28 +251,51 @@ pub struct State<T> {
     pub rb: RenderBuffer,
     pub timer: Timer,
     pub timer_state: TimerState,
+    pub source: TimerSource,
+    pub alarm: AlarmState,
+    pub chime: ChimeState,
+    pub burn_in: Option<BurnInState>,
     pub weather: T,
+    /// motion of weather and timer in frames per tick
+    pub speed: f32,
     frame: u64,
+    /// frames of weather and timer, they run at speed
+    motion_frame: u64,
+    /// fraction of a motion frame carried to the next tick
+    motion_carry: f32,
     rng: SmallRng,
     seed: u64,
 }
 
 impl<T: EachFrameImpl> State<T> {
-    pub fn new(size: Size, weather: T, args: crate::cli::Args) -> Self {
-        let mut timer_state = TimerState::new(size, args.timer_mode.map(|mode| mode.into()));
-        if args.blink_colon {
-            timer_state.colon.enable_blink();
-        }
+    pub fn new(size: Size, weather: T, args: &Args) -> anyhow::Result<Self> {
+        let source = TimerSource::from(args);
+
+        let mut timer_state = TimerState::new(
+            size,
+            args.timer_mode.map(|mode| mode.into()),
+            timer_face(args)?,
+            args.into(),
+            args.progress,
+        );
+        timer_state.colon.set_blink(args.blink_colon);
 
-        State {
+        Ok(State {
             rb: RenderBuffer::new(size),
             rng: SmallRng::from_entropy(),
             frame: 0,
-            timer: Timer::default(),
+            timer: source.timer(),
             timer_state,
+            source,
+            alarm: AlarmState::new(args.alarm.clone(), args.snooze),
+            chime: ChimeState::new(args.chime, args.chime_count, Timer::new()),
+            burn_in: args.burn_in_protection.then(|| BurnInState::new(args.night_hours)),
             seed: 0,
             weather,
-        }
+            speed: args.speed,
+            motion_frame: 0,
+            motion_carry: 0.,
+        })
     }
 
     pub fn on_resize(&mut self, columns: u16, rows: u16) {
@@ -237,19 +305,134 @@ impl<T: EachFrameImpl> State<T> {
         };
 
         self.rb = RenderBuffer::new(rect);
-        self.timer_state = TimerState::new(rect, self.timer_state.mode);
+        // the timer is placed again for the new size, keeping its mode, shift and colon blinking
+        let shift = self.timer_state.shift();
+        let colon = std::mem::take(&mut self.timer_state.colon);
+        self.timer_state = TimerState::new(
+            rect,
+            self.timer_state.mode,
+            self.timer_state.face.clone(),
+            self.timer_state.placement,
+            self.timer_state.progress,
+        );
+        self.timer_state.set_shift(shift);
+        self.timer_state.colon = colon;
     }
 
-    pub fn tick_timer(&mut self) {
-        self.timer = Timer::new();
+    /// apply reloaded args over the old ones. the timer stays where it is, and stopwatch, pomodoro, alarms,
+    /// chimes and burn-in protection are rebuilt only when their options changed
+    pub fn apply_args(&mut self, old: &Args, args: &Args) -> anyhow::Result<()> {
+        self.timer_state.set_face(timer_face(args)?, args.progress);
+        self.timer_state.set_placement(args.into());
+        self.timer_state.colon.set_blink(args.blink_colon);
+        if old.timer_mode != args.timer_mode {
+            self.timer_state.set_mode(args.timer_mode.map(|mode| mode.into()));
+        }
+        self.speed = args.speed;
+
+        if (old.stopwatch, old.pomodoro, PomodoroConfig::from(old)) != (args.stopwatch, args.pomodoro, args.into()) {
+            self.source = TimerSource::from(args);
+            self.update_timer();
+        }
+        if (&old.alarm, old.snooze) != (&args.alarm, args.snooze) {
+            self.alarm = AlarmState::new(args.alarm.clone(), args.snooze);
+        }
+        if (old.chime, old.chime_count) != (args.chime, args.chime_count) {
+            self.chime = ChimeState::new(args.chime, args.chime_count, Timer::new());
+        }
+        if (old.burn_in_protection, old.night_hours) != (args.burn_in_protection, args.night_hours) {
+            self.burn_in = args.burn_in_protection.then(|| BurnInState::new(args.night_hours));
+            self.timer_state.set_shift(self.burn_in.as_ref().map_or((0, 0), |burn_in| burn_in.shift()));
+        }
+
+        Ok(())
     }
 
-    pub fn tick(&mut self) -> ShouldRender {
+    /// called each second, a paused timer does not jump
+    pub fn tick_timer(&mut self, paused: bool) {
+        self.update_timer();
+        if !paused {
+            self.timer_state.on_second(self.rng.next_u64());
+        }
+        if let Some(burn_in) = &mut self.burn_in {
+            burn_in.on_second();
+            self.timer_state.set_shift(burn_in.shift());
+        }
+    }
+
+    fn update_timer(&mut self) {
+        self.timer = self.source.timer();
+    }
+
+    pub fn handle_action(&mut self, action: Action) -> bool {
+        let handled = self.source.handle_action(action);
+        if handled {
+            self.update_timer();
+        }
+
+        handled
+    }
+
+    /// the pomodoro phase switched since last call
+    pub fn take_transition(&mut self) -> Option<Transition> {
+        self.source.take_transition()
+    }
+
+    fn tick_source(&mut self) -> ShouldRender {
+        let should_render = self.source.on_frame(&mut self.rb, self.seed, self.frame);
+        if should_render.is_render() {
+            self.update_timer();
+        }
+
+        should_render
+    }
+
+    fn next_frame(&mut self) {
         self.frame = if self.frame == u64::MAX { 0 } else { self.frame.saturating_add(1) };
         self.seed = self.rng.next_u64();
+    }
 
-        self.weather.on_frame(&mut self.rb, self.seed, self.frame)
-            .or(self.timer_state.on_frame(&mut self.rb, self.seed, self.frame))
+    /// timer source, progress, alarm and chime, which go on while paused
+    fn tick_clock(&mut self) -> ShouldRender {
+        self.tick_source()
+            .or(self.timer_state.tick_progress(self.frame))
+            .or(self.alarm.on_frame(&mut self.rb, self.seed, self.frame))
+            .or(self.chime.on_frame(&mut self.rb, self.seed, self.frame))
     }
-}
 
+    /// a frame of weather and timer
+    fn step_motion(&mut self) -> ShouldRender {
+        self.motion_frame = if self.motion_frame == u64::MAX { 0 } else { self.motion_frame.saturating_add(1) };
+        let seed = self.rng.next_u64();
+
+        self.weather.on_frame(&mut self.rb, seed, self.motion_frame)
+            .or(self.timer_state.on_frame(&mut self.rb, seed, self.motion_frame))
+    }
+
+    fn tick_with(&mut self, motion_frames: u32) -> ShouldRender {
+        self.next_frame();
+
+        (0..motion_frames)
+            .fold(ShouldRender::Skip, |should_render, _| should_render.or(self.step_motion()))
+            .or(self.tick_clock())
+    }
+
+    /// weather and timer move by speed frames, a fraction adds up over ticks
+    pub fn tick(&mut self) -> ShouldRender {
+        self.motion_carry += self.speed;
+        let motion_frames = self.motion_carry as u32;
+        self.motion_carry = self.motion_carry.fract();
+
+        self.tick_with(motion_frames)
+    }
+
+    /// tick without moving weather and timer
+    pub fn tick_paused(&mut self) -> ShouldRender {
+        self.tick_with(0)
+    }
+
+    /// tick moving weather and timer by a frame whatever the speed
+    pub fn tick_step(&mut self) -> ShouldRender {
+        self.tick_with(1)
+    }
+}
@@ -0,0 +1,166 @@
+use std::{fmt::Display, time::Duration};
+
+use crate::{app::Action, cli::Args};
+
+use super::{buffer::RenderBuffer, countdown::Countdown, EachFrameImpl, ShouldRender};
+
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub enum Phase {
+    Work,
+    ShortBreak,
+    LongBreak,
+}
+
+impl Phase {
+    pub fn is_break(&self) -> bool {
+        *self != Phase::Work
+    }
+}
+
+impl Display for Phase {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = match *self {
+            Phase::Work => "work",
+            Phase::ShortBreak => "short-break",
+            Phase::LongBreak => "long-break",
+        };
+
+        s.fmt(f)
+    }
+}
+
+#[derive(Copy, Clone)]
+pub struct Transition {
+    pub phase: Phase,
+    pub cycle: u16,
+}
+
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub struct PomodoroConfig {
+    pub work: Duration,
+    pub short_break: Duration,
+    pub long_break: Duration,
+    /// count of work sessions before a long break
+    pub long_break_interval: u16,
+}
+
+impl From<&Args> for PomodoroConfig {
+    fn from(args: &Args) -> Self {
+        let minutes = |m: u16| Duration::from_secs(m as u64 * 60);
+        Self {
+            work: minutes(args.work),
+            short_break: minutes(args.short_break),
+            long_break: minutes(args.long_break),
+            long_break_interval: args.long_break_interval,
+        }
+    }
+}
+
+pub struct Pomodoro {
+    pub phase: Phase,
+    /// current work session, starting from 1
+    pub cycle: u16,
+    config: PomodoroConfig,
+    countdown: Countdown,
+    transition: Option<Transition>,
+    secs: u64,
+}
+
+impl Pomodoro {
+    pub fn new(config: PomodoroConfig) -> Self {
+        let countdown = Countdown::new(config.work);
+        Self {
+            phase: Phase::Work,
+            cycle: 1,
+            secs: countdown.remaining_secs(),
+            config,
+            countdown,
+            transition: None,
+        }
+    }
+
+    pub fn remaining(&self) -> Duration {
+        Duration::from_secs(self.countdown.remaining_secs())
+    }
+
+    /// remaining time with its sub-second part
+    pub fn remaining_exact(&self) -> Duration {
+        self.countdown.remaining()
+    }
+
+    pub fn is_paused(&self) -> bool {
+        self.countdown.is_paused()
+    }
+
+    pub fn long_break_interval(&self) -> u16 {
+        self.config.long_break_interval
+    }
+
+    /// the phase switched since last call
+    pub fn take_transition(&mut self) -> Option<Transition> {
+        self.transition.take()
+    }
+
+    fn next_phase(&self) -> Phase {
+        match self.phase {
+            Phase::Work if self.cycle.is_multiple_of(self.config.long_break_interval) => Phase::LongBreak,
+            Phase::Work => Phase::ShortBreak,
+            Phase::ShortBreak | Phase::LongBreak => Phase::Work,
+        }
+    }
+
+    fn duration(&self, phase: Phase) -> Duration {
+        match phase {
+            Phase::Work => self.config.work,
+            Phase::ShortBreak => self.config.short_break,
+            Phase::LongBreak => self.config.long_break,
+        }
+    }
+
+    /// switch to next phase, keeping paused countdown paused
+    fn advance(&mut self) {
+        let next = self.next_phase();
+        if self.phase.is_break() {
+            self.cycle = self.cycle.saturating_add(1);
+        }
+
+        let paused = self.countdown.is_paused();
+        self.phase = next;
+        self.countdown = Countdown::new(self.duration(next));
+        if paused {
+            self.countdown.toggle();
+        }
+
+        self.transition = Some(Transition {
+            phase: next,
+            cycle: self.cycle,
+        });
+    }
+
+    /// handle start-stop and skip, return false for other actions
+    pub fn handle_action(&mut self, action: Action) -> bool {
+        match action {
+            Action::StartStop => self.countdown.toggle(),
+            Action::Skip => self.advance(),
+            _ => return false,
+        }
+
+        true
+    }
+}
+
+impl EachFrameImpl for Pomodoro {
+    fn on_frame(&mut self, _: &mut RenderBuffer, _: u64, _: u64) -> ShouldRender {
+        if self.countdown.is_finished() {
+            self.advance();
+        }
+
+        let secs = self.countdown.remaining_secs();
+        if secs == self.secs && self.transition.is_none() {
+            return ShouldRender::Skip;
+        }
+
+        self.secs = secs;
+        ShouldRender::Render
+    }
+}
@@ -0,0 +1,68 @@
+use std::time::{Duration, Instant};
+
+use crate::app::Action;
+
+use super::{buffer::RenderBuffer, EachFrameImpl, ShouldRender};
+
+#[derive(Default)]
+pub struct Stopwatch {
+    pub laps: Vec<Duration>,
+    started_at: Option<Instant>,
+    accumulated: Duration,
+    tenths: u128,
+}
+
+impl Stopwatch {
+    pub fn elapsed(&self) -> Duration {
+        match self.started_at {
+            Some(started_at) => self.accumulated + started_at.elapsed(),
+            None => self.accumulated,
+        }
+    }
+
+    pub fn is_running(&self) -> bool {
+        self.started_at.is_some()
+    }
+
+    pub fn toggle(&mut self) {
+        match self.started_at.take() {
+            Some(started_at) => self.accumulated += started_at.elapsed(),
+            None => self.started_at = Some(Instant::now()),
+        }
+    }
+
+    pub fn lap(&mut self) {
+        if self.is_running() {
+            self.laps.push(self.elapsed());
+        }
+    }
+
+    pub fn reset(&mut self) {
+        *self = Self::default();
+    }
+
+    /// handle start-stop, lap and reset, return false for other actions
+    pub fn handle_action(&mut self, action: Action) -> bool {
+        match action {
+            Action::StartStop => self.toggle(),
+            Action::Lap => self.lap(),
+            Action::Reset => self.reset(),
+            _ => return false,
+        }
+
+        true
+    }
+}
+
+impl EachFrameImpl for Stopwatch {
+    /// render again only when the tenth of second has changed
+    fn on_frame(&mut self, _: &mut RenderBuffer, _: u64, _: u64) -> ShouldRender {
+        let tenths = self.elapsed().as_millis() / 100;
+        if tenths == self.tenths {
+            return ShouldRender::Skip;
+        }
+
+        self.tenths = tenths;
+        ShouldRender::Render
+    }
+}
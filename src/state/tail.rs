@@ -1,6 +1,6 @@
 use std::cell::RefCell;
 
-use super::{buffer::RenderBuffer, wind::WindMode, Cell, CellType, Column, EachFrameImpl, ShouldRender};
+use super::{buffer::RenderBuffer, wind::WindMode, Cell, CellType, Column, EachFrameImpl, Particle, ShouldRender};
 
 #[derive(Default, Copy, Clone, Eq, PartialEq)]
 pub enum TailMode {
@@ -32,12 +32,12 @@ impl TailState {
     }
 
     fn remove_tail_from_cell(cell: Cell) -> Cell {
-        cell.into_iter().filter(|t| *t != CellType::Tail).collect()
+        cell.into_iter().filter(|t| t.kind != CellType::Tail).collect()
     }
 
     fn append_tail(mut cell: Cell) -> Cell {
-        if !cell.contains(&CellType::Tail) {
-            cell.push(CellType::Tail);
+        if !cell.iter().any(|t| t.kind == CellType::Tail) {
+            cell.push(Particle::new(CellType::Tail, 0));
         }
 
         cell
@@ -54,7 +54,7 @@ impl TailState {
                         continue;
                     }
 
-                    if !cell.iter().any(|t| t.is_dropping_cell()) {
+                    if !cell.iter().any(|t| t.kind.is_dropping_cell()) {
                         continue;
                     }
 
@@ -86,7 +86,7 @@ impl TailState {
                         continue;
                     }
 
-                    if !cell.iter().any(|t| t.is_dropping_cell()) {
+                    if !cell.iter().any(|t| t.kind.is_dropping_cell()) {
                         continue;
                     }
 
@@ -1,18 +1,34 @@
-use std::{fmt::Display, time::SystemTime};
+use std::{
+    f32::consts::TAU,
+    fmt::Display,
+    rc::Rc,
+    time::{Duration, SystemTime},
+};
 
 use chrono::{DateTime, Local, Timelike};
 use clap::ValueEnum;
 use ratatui::layout::{Rect, Size};
 
-use crate::widget::timer::{TIMER_LAYOUT_HEIGHT, TIMER_LAYOUT_WIDTH};
+use crate::{
+    app::Action,
+    cli::Args,
+    widget::timer::{progress::Progress, TimerFace},
+};
 
-use super::{buffer::RenderBuffer, Direction, EachFrameImpl, Position, ShouldRender};
+use super::{
+    buffer::RenderBuffer,
+    pomodoro::{Phase, Pomodoro, Transition},
+    stopwatch::Stopwatch,
+    Direction, EachFrameImpl, Position, ShouldRender,
+};
 
 #[derive(Copy, Clone)]
 pub struct Timer {
     pub hours: u8,
     pub minutes: u8,
     pub seconds: u8,
+    /// tenth of second, only shown by sources with sub-second precision
+    pub tenths: Option<u8>,
 }
 
 impl Timer {
@@ -21,6 +37,18 @@ impl Timer {
     }
 }
 
+impl From<Duration> for Timer {
+    fn from(value: Duration) -> Self {
+        let secs = value.as_secs();
+        Self {
+            hours: (secs / 3600 % 100) as u8,
+            minutes: (secs / 60 % 60) as u8,
+            seconds: (secs % 60) as u8,
+            tenths: None,
+        }
+    }
+}
+
 impl Default for Timer {
     fn default() -> Self {
         let system_time = SystemTime::now();
@@ -29,20 +57,130 @@ impl Default for Timer {
             hours: datetime.hour() as u8,
             minutes: datetime.minute() as u8,
             seconds: datetime.second() as u8,
+            tenths: None,
+        }
+    }
+}
+
+/// where the time shown by the timer comes from
+pub enum TimerSource {
+    Wall,
+    Stopwatch(Stopwatch),
+    Pomodoro(Pomodoro),
+}
+
+impl From<&Args> for TimerSource {
+    fn from(args: &Args) -> Self {
+        if args.stopwatch {
+            Self::Stopwatch(Stopwatch::default())
+        } else if args.pomodoro {
+            Self::Pomodoro(Pomodoro::new(args.into()))
+        } else {
+            Self::Wall
+        }
+    }
+}
+
+impl TimerSource {
+    pub fn timer(&self) -> Timer {
+        match self {
+            Self::Wall => Timer::new(),
+            Self::Stopwatch(stopwatch) => {
+                let elapsed = stopwatch.elapsed();
+                Timer {
+                    tenths: Some((elapsed.subsec_millis() / 100) as u8),
+                    ..elapsed.into()
+                }
+            }
+            Self::Pomodoro(pomodoro) => pomodoro.remaining().into(),
+        }
+    }
+
+    /// fraction of the minute passed, or left of a countdown, with the sub-second part of the source
+    pub fn minute_fraction(&self) -> f32 {
+        let seconds = match self {
+            Self::Wall => {
+                let now = Local::now();
+                now.second() as f64 + now.nanosecond().min(999_999_999) as f64 / 1e9
+            }
+            Self::Stopwatch(stopwatch) => stopwatch.elapsed().as_secs_f64() % 60.,
+            Self::Pomodoro(pomodoro) => pomodoro.remaining_exact().as_secs_f64() % 60.,
+        };
+
+        (seconds / 60.) as f32
+    }
+
+    /// the action is used by stopwatch or pomodoro
+    pub fn handle_action(&mut self, action: Action) -> bool {
+        match self {
+            Self::Wall => false,
+            Self::Stopwatch(stopwatch) => stopwatch.handle_action(action),
+            Self::Pomodoro(pomodoro) => pomodoro.handle_action(action),
+        }
+    }
+
+    pub fn phase(&self) -> Option<Phase> {
+        match self {
+            Self::Pomodoro(pomodoro) => Some(pomodoro.phase),
+            _ => None,
+        }
+    }
+
+    pub fn take_transition(&mut self) -> Option<Transition> {
+        match self {
+            Self::Pomodoro(pomodoro) => pomodoro.take_transition(),
+            _ => None,
+        }
+    }
+}
+
+impl EachFrameImpl for TimerSource {
+    fn on_frame(&mut self, rb: &mut RenderBuffer, seed: u64, frame: u64) -> ShouldRender {
+        match self {
+            Self::Wall => ShouldRender::Skip,
+            Self::Stopwatch(stopwatch) => stopwatch.on_frame(rb, seed, frame),
+            Self::Pomodoro(pomodoro) => pomodoro.on_frame(rb, seed, frame),
         }
     }
 }
 
 /// enum alias for parsed from cli
-#[derive(Copy, Clone, ValueEnum)]
+#[derive(Copy, Clone, PartialEq, Eq, ValueEnum)]
 pub enum TimerMode {
+    /// bounce off the edges
     Dvd,
+    /// smooth random drift
+    Wander,
+    /// jump between corners every minute
+    Corners,
+    /// move on an ellipse
+    Orbit,
+    /// random position every minute
+    Teleport,
+}
+
+impl TimerMode {
+    /// timer mode switched to by key, a still timer comes after the last one
+    pub fn next(mode: Option<Self>) -> Option<Self> {
+        match mode {
+            None => Some(TimerMode::Dvd),
+            Some(TimerMode::Dvd) => Some(TimerMode::Wander),
+            Some(TimerMode::Wander) => Some(TimerMode::Corners),
+            Some(TimerMode::Corners) => Some(TimerMode::Orbit),
+            Some(TimerMode::Orbit) => Some(TimerMode::Teleport),
+            Some(TimerMode::Teleport) => None,
+        }
+    }
 }
 
 impl Display for TimerMode {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let s = match *self {
             TimerMode::Dvd => "dvd",
+            TimerMode::Wander => "wander",
+            TimerMode::Corners => "corners",
+            TimerMode::Orbit => "orbit",
+            TimerMode::Teleport => "teleport",
         };
 
         s.fmt(f)
@@ -52,6 +190,13 @@ impl Display for TimerMode {
 #[derive(Copy, Clone)]
 pub enum TimerRenderMode {
     Dvd(Direction),
+    /// sub-cell position and velocity in cells per frame
+    Wander { x: f32, y: f32, dx: f32, dy: f32 },
+    /// index of the next corner
+    Corners(usize),
+    /// angle on the ellipse
+    Orbit(f32),
+    Teleport,
 }
 
 impl Default for TimerRenderMode {
@@ -64,10 +209,81 @@ impl From<TimerMode> for TimerRenderMode {
     fn from(value: TimerMode) -> Self {
         match value {
             TimerMode::Dvd => Self::Dvd(Direction::default()),
+            TimerMode::Wander => Self::Wander {
+                x: 0.0,
+                y: 0.0,
+                dx: WANDER_SPEED,
+                dy: WANDER_SPEED / 2.0,
+            },
+            TimerMode::Corners => Self::Corners(0),
+            TimerMode::Orbit => Self::Orbit(0.0),
+            TimerMode::Teleport => Self::Teleport,
         }
     }
 }
 
+/// where the timer is placed on the screen
+#[derive(Copy, Clone, Default, PartialEq, Eq, ValueEnum)]
+pub enum Anchor {
+    #[default]
+    Center,
+    Top,
+    Bottom,
+    Left,
+    Right,
+    TopLeft,
+    TopRight,
+    BottomLeft,
+    BottomRight,
+}
+
+impl Display for Anchor {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = match *self {
+            Anchor::Center => "center",
+            Anchor::Top => "top",
+            Anchor::Bottom => "bottom",
+            Anchor::Left => "left",
+            Anchor::Right => "right",
+            Anchor::TopLeft => "top-left",
+            Anchor::TopRight => "top-right",
+            Anchor::BottomLeft => "bottom-left",
+            Anchor::BottomRight => "bottom-right",
+        };
+
+        s.fmt(f)
+    }
+}
+
+/// anchor of the timer, moved by offset and kept away from edges by margin
+#[derive(Copy, Clone, Default, PartialEq, Eq)]
+pub struct Placement {
+    pub anchor: Anchor,
+    pub offset: (i16, i16),
+    pub margin: u16,
+}
+
+impl From<&Args> for Placement {
+    fn from(args: &Args) -> Self {
+        Self {
+            anchor: args.timer_position,
+            offset: args.timer_offset,
+            margin: args.timer_margin,
+        }
+    }
+}
+
+/// max speed of wander mode in cells per frame
+const WANDER_SPEED: f32 = 0.15;
+/// change of wander velocity per frame
+const WANDER_ACCEL: f32 = 0.01;
+/// angle of orbit mode per frame, a round in about a minute
+const ORBIT_SPEED: f32 = TAU / 3000.0;
+/// seconds between jumps of corners and teleport modes
+const JUMP_INTERVAL: u16 = 60;
+/// ticks between renders of progress, smoothing it within a second
+const PROGRESS_FRAMES: u64 = 4;
+
 pub struct ColonState {
     pub show: bool,
     blink: bool,
@@ -83,8 +299,11 @@ impl Default for ColonState {
 }
 
 impl ColonState {
-    pub fn enable_blink(&mut self) {
-        self.blink = true;
+    pub fn set_blink(&mut self, blink: bool) {
+        self.blink = blink;
+        if !blink {
+            self.show = true;
+        }
     }
 
     fn toggle(&mut self) {
@@ -108,23 +327,223 @@ pub struct TimerState {
     pub area: Rect,
     pub pos: Position,
     pub boundary: Size,
+    /// size of the timer face
+    pub layout: Size,
+    pub face: Rc<dyn TimerFace>,
     pub colon: ColonState,
+    /// seconds since the last jump
+    seconds: u16,
+    pub placement: Placement,
+    pub progress: Option<Progress>,
+    /// offset of area from pos by burn-in protection
+    shift: (i16, i16),
 }
 
 impl TimerState {
-    pub fn new(area: Size, mode: Option<TimerRenderMode>) -> Self {
+    pub fn new(
+        area: Size,
+        mode: Option<TimerRenderMode>,
+        face: Rc<dyn TimerFace>,
+        placement: Placement,
+        progress: Option<Progress>,
+    ) -> Self {
         let boundary = area;
-        let area = Self::get_center_area(area);
+        let layout = Self::get_layout(boundary, face.as_ref(), progress);
+        let area = Self::get_anchored_area(area, layout, placement);
 
         Self {
-            mode,
+            mode: Self::start_mode(mode, area.into()),
             area,
             boundary,
+            layout,
+            face,
             pos: area.into(),
             colon: ColonState::default(),
+            placement,
+            progress,
+            seconds: 0,
+            shift: (0, 0),
+        }
+    }
+
+    /// wander starts from where the timer is
+    fn start_mode(mode: Option<TimerRenderMode>, pos: Position) -> Option<TimerRenderMode> {
+        mode.map(|mode| match mode {
+            TimerRenderMode::Wander { dx, dy, .. } => TimerRenderMode::Wander {
+                x: pos.0 as f32,
+                y: pos.1 as f32,
+                dx,
+                dy,
+            },
+            mode => mode,
+        })
+    }
+
+    /// switch mode from the current position, a still timer goes back to its placement
+    pub fn set_mode(&mut self, mode: Option<TimerRenderMode>) {
+        self.mode = Self::start_mode(mode, self.pos);
+        self.seconds = 0;
+        if self.mode.is_none() {
+            self.anchor();
         }
     }
 
+    /// a still timer moves to the new placement, a moving one goes on from where it is
+    pub fn set_placement(&mut self, placement: Placement) {
+        self.placement = placement;
+        if self.mode.is_none() {
+            self.anchor();
+        }
+    }
+
+    fn anchor(&mut self) {
+        let area = Self::get_anchored_area(self.boundary, self.layout, self.placement);
+        self.pos = area.into();
+        self.area = self.get_area_with_pos();
+    }
+
+    /// size of the face with the progress around it
+    fn get_layout(boundary: Size, face: &dyn TimerFace, progress: Option<Progress>) -> Size {
+        let extra = progress.map_or(Size::ZERO, |progress| progress.extra());
+        let layout = face.layout(Self::shrink(boundary, extra));
+
+        Size {
+            width: layout.width + extra.width,
+            height: layout.height + extra.height,
+        }
+    }
+
+    fn shrink(size: Size, by: Size) -> Size {
+        Size {
+            width: size.width.saturating_sub(by.width),
+            height: size.height.saturating_sub(by.height),
+        }
+    }
+
+    /// screen size given to the face
+    pub fn face_boundary(&self) -> Size {
+        let extra = self.progress.map_or(Size::ZERO, |progress| progress.extra());
+        Self::shrink(self.boundary, extra)
+    }
+
+    /// area the face is rendered into
+    pub fn face_area(&self) -> Rect {
+        self.progress.map_or(self.area, |progress| progress.inner(self.area))
+    }
+
+    /// swap face and progress keeping the position
+    pub fn set_face(&mut self, face: Rc<dyn TimerFace>, progress: Option<Progress>) {
+        self.layout = Self::get_layout(self.boundary, face.as_ref(), progress);
+        self.face = face;
+        self.progress = progress;
+
+        let (max_x, max_y) = self.max_pos();
+        self.pos = Position(self.pos.0.min(max_x), self.pos.1.min(max_y));
+        self.area = self.get_area_with_pos();
+    }
+
+    /// progress goes on with the clock, so it is rendered by ticks rather than motion frames
+    pub fn tick_progress(&self, frame: u64) -> ShouldRender {
+        if self.progress.is_some() && frame.is_multiple_of(PROGRESS_FRAMES) {
+            ShouldRender::Render
+        } else {
+            ShouldRender::Skip
+        }
+    }
+
+    pub fn shift(&self) -> (i16, i16) {
+        self.shift
+    }
+
+    /// move area away from pos, it is kept inside boundary
+    pub fn set_shift(&mut self, shift: (i16, i16)) {
+        self.shift = shift;
+        self.area = self.get_area_with_pos();
+    }
+
+    /// the largest position keeping the timer inside boundary
+    fn max_pos(&self) -> (u16, u16) {
+        (
+            self.boundary.width.saturating_sub(self.layout.width),
+            self.boundary.height.saturating_sub(self.layout.height),
+        )
+    }
+
+    fn on_wander_frame(&mut self, seed: u64) {
+        let Some(TimerRenderMode::Wander { x, y, dx, dy }) = self.mode else {
+            return;
+        };
+
+        // nudge velocity by random bits of seed, cells are about twice as tall as wide
+        let nudge = |bits: u64| (bits & 0xff) as f32 / 255.0 * 2.0 - 1.0;
+        let dx = (dx + nudge(seed) * WANDER_ACCEL).clamp(-WANDER_SPEED, WANDER_SPEED);
+        let dy = (dy + nudge(seed >> 8) * WANDER_ACCEL / 2.0).clamp(-WANDER_SPEED / 2.0, WANDER_SPEED / 2.0);
+
+        let (max_x, max_y) = self.max_pos();
+        let (x, dx) = Self::bounce(x + dx, dx, max_x as f32);
+        let (y, dy) = Self::bounce(y + dy, dy, max_y as f32);
+
+        self.pos = Position(x.round() as u16, y.round() as u16);
+        self.mode = Some(TimerRenderMode::Wander { x, y, dx, dy });
+    }
+
+    /// keep value in `0..=max`, reflect velocity when hitting the edge
+    fn bounce(v: f32, dv: f32, max: f32) -> (f32, f32) {
+        if v <= 0.0 {
+            (0.0, dv.abs())
+        } else if v >= max {
+            (max, -dv.abs())
+        } else {
+            (v, dv)
+        }
+    }
+
+    fn on_orbit_frame(&mut self) {
+        let Some(TimerRenderMode::Orbit(angle)) = self.mode else {
+            return;
+        };
+
+        let angle = (angle + ORBIT_SPEED) % TAU;
+        let (max_x, max_y) = self.max_pos();
+        let (rx, ry) = (max_x as f32 / 2.0, max_y as f32 / 2.0);
+
+        self.pos = Position(
+            (rx + rx * angle.cos()).round() as u16,
+            (ry + ry * angle.sin()).round() as u16,
+        );
+        self.mode = Some(TimerRenderMode::Orbit(angle));
+    }
+
+    /// jump modes move once every `JUMP_INTERVAL` seconds
+    pub fn on_second(&mut self, seed: u64) {
+        if !matches!(self.mode, Some(TimerRenderMode::Corners(_) | TimerRenderMode::Teleport)) {
+            return;
+        }
+
+        self.seconds += 1;
+        if self.seconds < JUMP_INTERVAL {
+            return;
+        }
+
+        self.seconds = 0;
+        let (max_x, max_y) = self.max_pos();
+        match self.mode {
+            Some(TimerRenderMode::Corners(i)) => {
+                let (x, y) = [(0, 0), (max_x, 0), (max_x, max_y), (0, max_y)][i % 4];
+                self.pos = Position(x, y);
+                self.mode = Some(TimerRenderMode::Corners((i + 1) % 4));
+            }
+            Some(TimerRenderMode::Teleport) => {
+                let x = (seed % (max_x as u64 + 1)) as u16;
+                let y = ((seed >> 32) % (max_y as u64 + 1)) as u16;
+                self.pos = Position(x, y);
+            }
+            _ => (),
+        }
+
+        self.area = self.get_area_with_pos();
+    }
+
     fn on_dvd_frame(&mut self) {
         let Some(TimerRenderMode::Dvd(dir)) = self.mode else {
             return;
@@ -147,51 +566,75 @@ impl TimerState {
         self.mode = Some(TimerRenderMode::Dvd(dir));
     }
 
-    fn get_center_area(area: Size) -> Rect {
-        let padding_h = (area.width.saturating_sub(TIMER_LAYOUT_WIDTH)) / 2;
-        let padding_v = (area.height.saturating_sub(TIMER_LAYOUT_HEIGHT)) / 2;
+    fn get_anchored_area(area: Size, layout: Size, placement: Placement) -> Rect {
+        use Anchor::*;
+        let Placement { anchor, offset, margin } = placement;
+        let max_x = area.width.saturating_sub(layout.width);
+        let max_y = area.height.saturating_sub(layout.height);
+
+        let x = match anchor {
+            Left | TopLeft | BottomLeft => margin,
+            Right | TopRight | BottomRight => max_x.saturating_sub(margin),
+            Center | Top | Bottom => max_x / 2,
+        };
+        let y = match anchor {
+            Top | TopLeft | TopRight => margin,
+            Bottom | BottomLeft | BottomRight => max_y.saturating_sub(margin),
+            Center | Left | Right => max_y / 2,
+        };
 
         Rect {
-            x: padding_h,
-            y: padding_v,
-            height: TIMER_LAYOUT_HEIGHT,
-            width: TIMER_LAYOUT_WIDTH,
+            x: x.saturating_add_signed(offset.0).min(max_x),
+            y: y.saturating_add_signed(offset.1).min(max_y),
+            height: layout.height,
+            width: layout.width,
         }
     }
 
-    fn get_area_with_pos(pos: Position) -> Rect {
-        pos.into_rect(TIMER_LAYOUT_WIDTH, TIMER_LAYOUT_HEIGHT)
+    fn get_area_with_pos(&self) -> Rect {
+        let (max_x, max_y) = self.max_pos();
+        let shift = |v: u16, d: i16, max: u16| v.saturating_add_signed(d).min(max.max(v));
+        let pos = Position(shift(self.pos.0, self.shift.0, max_x), shift(self.pos.1, self.shift.1, max_y));
+        pos.into_rect(self.layout.width, self.layout.height)
     }
 
     fn is_collision_v(&self) -> bool {
-        self.pos.0 == 0 || (self.pos.0 + TIMER_LAYOUT_WIDTH) >= self.boundary.width
+        self.pos.0 == 0 || (self.pos.0 + self.layout.width) >= self.boundary.width
     }
 
     fn is_collision_h(&self) -> bool {
-        self.pos.1 == 0 || (self.pos.1 + TIMER_LAYOUT_HEIGHT) >= self.boundary.height
+        self.pos.1 == 0 || (self.pos.1 + self.layout.height) >= self.boundary.height
     }
 
-    fn handle_mode(&mut self, frame: u64) -> ShouldRender {
-        if self.mode.is_none() {
+    fn handle_mode(&mut self, seed: u64, frame: u64) -> ShouldRender {
+        let Some(mode) = self.mode else {
             return ShouldRender::Skip;
-        }
+        };
 
         if frame.is_multiple_of(8) {
             return ShouldRender::Skip;
         }
 
-        match self.mode.unwrap() {
+        let pos = self.pos;
+        match mode {
             TimerRenderMode::Dvd(_) => self.on_dvd_frame(),
+            TimerRenderMode::Wander { .. } => self.on_wander_frame(seed),
+            TimerRenderMode::Orbit(_) => self.on_orbit_frame(),
+            TimerRenderMode::Corners(_) | TimerRenderMode::Teleport => (),
+        }
+
+        if self.pos == pos {
+            return ShouldRender::Skip;
         }
 
-        self.area = Self::get_area_with_pos(self.pos);
+        self.area = self.get_area_with_pos();
         ShouldRender::Render
     }
 }
 
 impl EachFrameImpl for TimerState {
     fn on_frame(&mut self, rb: &mut RenderBuffer, seed: u64, frame: u64) -> ShouldRender {
-        self.handle_mode(frame)
+        self.handle_mode(seed, frame)
             .or(self.colon.on_frame(rb, seed, frame))
     }
 }
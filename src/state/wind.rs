@@ -1,3 +1,5 @@
+use std::fmt::Display;
+
 use super::{buffer::RenderBuffer, EachFrameImpl, ShouldRender};
 
 #[derive(Clone, Copy, Default, Eq, PartialEq)]
@@ -20,6 +22,16 @@ impl WindMode {
         }
     }
 
+    /// wind mode switched to by key
+    pub fn next(self) -> Self {
+        match self {
+            WindMode::Random => WindMode::Disable,
+            WindMode::Disable => WindMode::OnlyRight,
+            WindMode::OnlyRight => WindMode::OnlyLeft,
+            WindMode::OnlyLeft => WindMode::Random,
+        }
+    }
+
     pub fn without_random(self) -> Self {
         match self {
             WindMode::Random => Self::Disable,
@@ -28,6 +40,19 @@ impl WindMode {
     }
 }
 
+impl Display for WindMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = match *self {
+            WindMode::Random => "random",
+            WindMode::Disable => "disable",
+            WindMode::OnlyRight => "only-right",
+            WindMode::OnlyLeft => "only-left",
+        };
+
+        s.fmt(f)
+    }
+}
+
 #[derive(Clone, Copy, Default, Eq, PartialEq)]
 pub enum WindDirection {
     Left,
@@ -0,0 +1,180 @@
+use std::{fmt::Display, str::FromStr, sync::LazyLock};
+
+use anyhow::{anyhow, Context, Result};
+use clap::ValueEnum;
+use ratatui::style::Color;
+use toml_edit::{ImDocument, Table};
+
+use crate::{
+    config::{config_dir, LineError},
+    state::Mode,
+    widget::{
+        timer::{color::TimerColor, digital::TimerGlyphs},
+        weather::{WeatherStyle, WeatherStyles},
+    },
+};
+
+const DEFAULT_TOML: &str = include_str!("themes/default.toml");
+
+const BUNDLED_THEMES: [(&str, &str); 5] = [
+    (DEFAULT_THEME, DEFAULT_TOML),
+    ("solarized", include_str!("themes/solarized.toml")),
+    ("nord", include_str!("themes/nord.toml")),
+    ("tokyo-night", include_str!("themes/tokyo-night.toml")),
+    ("mono", include_str!("themes/mono.toml")),
+];
+
+pub const DEFAULT_THEME: &str = "default";
+
+/// glyphs of the bundled default theme, parsed once
+static DEFAULT_GLYPHS: LazyLock<(WeatherStyles, TimerGlyphs)> = LazyLock::new(|| {
+    let blank = Theme {
+        timer_color: None,
+        timer_glyphs: TimerGlyphs { block: ' ', colon: ' ' },
+        styles: WeatherStyles::blank(),
+    };
+    let theme = Theme::parse(DEFAULT_TOML, blank).expect("bundled default theme is valid");
+    (theme.styles, theme.timer_glyphs)
+});
+
+/// styles other themes are laid over
+pub fn default_styles() -> WeatherStyles {
+    DEFAULT_GLYPHS.0
+}
+
+/// timer glyphs other themes are laid over
+pub fn default_timer_glyphs() -> TimerGlyphs {
+    DEFAULT_GLYPHS.1
+}
+
+/// colors and glyphs of effects and the timer
+pub struct Theme {
+    /// used when `--timer-color` is not given
+    pub timer_color: Option<TimerColor>,
+    pub timer_glyphs: TimerGlyphs,
+    pub styles: WeatherStyles,
+}
+
+impl Theme {
+    /// load `<config dir>/themes/<name>.toml`, or a bundled theme
+    pub fn load(name: &str) -> Result<Self> {
+        let path = config_dir().map(|dir| dir.join("themes").join(format!("{name}.toml")));
+        if let Some(path) = path.filter(|path| path.exists()) {
+            let text = std::fs::read_to_string(&path).with_context(|| format!("can not read theme `{}`", path.display()))?;
+            return Self::parse(&text, Self::over_default()).map_err(|e| anyhow!("{}:{e}", path.display()));
+        }
+
+        let (_, text) = BUNDLED_THEMES
+            .iter()
+            .find(|(n, _)| *n == name)
+            .ok_or_else(|| anyhow!("unknown theme `{name}`, bundled themes are {}", BundledThemes))?;
+
+        Self::parse(text, Self::over_default()).map_err(|e| anyhow!("bundled theme {name}:{e}"))
+    }
+
+    /// glyphs and styles of the default theme, without its timer color
+    fn over_default() -> Self {
+        Self {
+            timer_color: None,
+            timer_glyphs: default_timer_glyphs(),
+            styles: default_styles(),
+        }
+    }
+
+    /// `timer-color`, `timer-glyph`, `colon-glyph` and tables of styles named by mode, over the given theme
+    fn parse(text: &str, base: Self) -> Result<Self, LineError> {
+        let doc = ImDocument::parse(text).map_err(|e| LineError::new(text, e.span(), e.message()))?;
+        let mut theme = base;
+
+        for (key, item) in doc.iter() {
+            let span = doc.key(key).and_then(|key| key.span()).or(item.span());
+            let err = |message: String| LineError::new(text, span.clone(), &message);
+
+            if key == "timer-color" {
+                let value = item.as_str().ok_or_else(|| err("`timer-color` must be a string".to_string()))?;
+                theme.timer_color = Some(TimerColor::from_str(value).map_err(err)?);
+                continue;
+            }
+
+            if key == "timer-glyph" || key == "colon-glyph" {
+                let value = item.as_str().ok_or_else(|| err(format!("`{key}` must be a string")))?;
+                let mut chars = value.chars();
+                let (Some(c), None) = (chars.next(), chars.next()) else {
+                    return Err(err(format!("{key} must be a single character, found `{value}`")));
+                };
+
+                if key == "timer-glyph" {
+                    theme.timer_glyphs.block = c;
+                } else {
+                    theme.timer_glyphs.colon = c;
+                }
+                continue;
+            }
+
+            let style = Mode::from_str(key, false)
+                .ok()
+                .and_then(|mode| theme.styles.get_mut(mode))
+                .ok_or_else(|| err(format!("unknown key `{key}`, expected timer-color, timer-glyph, colon-glyph, rain, snow or meteor")))?;
+            let table = item.as_table().ok_or_else(|| err(format!("`{key}` must be a table")))?;
+            StylePatch::parse(text, table)?.apply(style);
+        }
+
+        Ok(theme)
+    }
+}
+
+/// fields of a style given by a table of theme or config
+#[derive(Copy, Clone, Default)]
+pub struct StylePatch {
+    color: Option<Color>,
+    glyphs: Option<[char; 3]>,
+    tails: Option<[char; 3]>,
+}
+
+impl StylePatch {
+    /// `color`, `glyph` for all speeds, `glyphs` of fast, normal and slow, `tails` to the left, right and straight
+    pub fn parse(text: &str, table: &Table) -> Result<Self, LineError> {
+        let mut patch = Self::default();
+
+        for (key, item) in table.iter() {
+            let span = table.key(key).and_then(|key| key.span()).or(item.span());
+            let err = |message: String| LineError::new(text, span.clone(), &message);
+            let value = item.as_str().ok_or_else(|| err(format!("`{key}` must be a string")))?;
+            let chars = value.chars().collect::<Vec<_>>();
+
+            match key {
+                "color" => patch.color = Some(Color::from_str(value).map_err(|_| err(format!("invalid color `{value}`")))?),
+                "glyph" => match chars[..] {
+                    [c] => patch.glyphs = Some([c; 3]),
+                    _ => return Err(err(format!("glyph must be a single character, found `{value}`"))),
+                },
+                "glyphs" | "tails" => {
+                    let glyphs = <[char; 3]>::try_from(chars).map_err(|_| err(format!("{key} must be 3 characters, found `{value}`")))?;
+                    if key == "glyphs" {
+                        patch.glyphs = Some(glyphs);
+                    } else {
+                        patch.tails = Some(glyphs);
+                    }
+                }
+                _ => return Err(err(format!("unknown key `{key}` of style, expected color, glyph, glyphs or tails"))),
+            }
+        }
+
+        Ok(patch)
+    }
+
+    pub fn apply(&self, style: &mut WeatherStyle) {
+        style.color = self.color.unwrap_or(style.color);
+        style.glyphs = self.glyphs.unwrap_or(style.glyphs);
+        style.tails = self.tails.unwrap_or(style.tails);
+    }
+}
+
+struct BundledThemes;
+
+impl Display for BundledThemes {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let names = BUNDLED_THEMES.map(|(name, _)| name);
+        names.join(", ").fmt(f)
+    }
+}
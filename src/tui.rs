@@ -1,3 +1,5 @@
+use std::path::PathBuf;
+
 use anyhow::Result;
 use crossterm::event::{Event as CrosstermEvent, KeyEvent, KeyEventKind};
 use futures::{FutureExt, StreamExt};
@@ -19,6 +21,8 @@ pub enum Event {
     Timer,
     Key(KeyEvent),
     Resize(u16, u16),
+    /// config file changed on disk
+    Reload,
 }
 
 pub struct Tui {
@@ -27,6 +31,7 @@ pub struct Tui {
     event_rx: UnboundedReceiver<Event>,
     event_tx: UnboundedSender<Event>,
     task: Option<JoinHandle<()>>,
+    watch_task: Option<JoinHandle<()>>,
 }
 
 impl Tui {
@@ -35,6 +40,7 @@ impl Tui {
         let task = None;
         Ok(Self {
             task,
+            watch_task: None,
             event_rx,
             event_tx,
             frame_rate,
@@ -68,10 +74,8 @@ impl Tui {
                         match maybe_event {
                             Some(Ok(evt)) => {
                                 match evt {
-                                    CrosstermEvent::Key(key) => {
-                                        if key.kind == KeyEventKind::Press {
-                                            _event_tx.send(Event::Key(key)).unwrap();
-                                        }
+                                    CrosstermEvent::Key(key) if key.kind == KeyEventKind::Press => {
+                                        _event_tx.send(Event::Key(key)).unwrap();
                                     },
                                     CrosstermEvent::Resize(x, y) => {
                                         _event_tx.send(Event::Resize(x, y)).unwrap();
@@ -103,6 +107,39 @@ impl Tui {
         self.task = Some(task);
     }
 
+    /// restart events at new rates
+    pub fn set_rates(&mut self, frame_rate: f64, tick_rate: f64) {
+        if let Some(task) = self.task.take() {
+            task.abort();
+        }
+
+        self.frame_rate = frame_rate;
+        self.tick_rate = tick_rate;
+        self.run();
+    }
+
+    /// poll modified time of the file each second, send `Event::Reload` when it changes
+    pub fn watch(&mut self, path: PathBuf) {
+        let _event_tx = self.event_tx.clone();
+        let modified = move || std::fs::metadata(&path).and_then(|meta| meta.modified()).ok();
+
+        let task = tokio::spawn(async move {
+            let mut last = modified();
+            let mut interval = tokio::time::interval(std::time::Duration::from_secs(1));
+
+            loop {
+                interval.tick().await;
+                let current = modified();
+                if current != last {
+                    last = current;
+                    _event_tx.send(Event::Reload).unwrap();
+                }
+            }
+        });
+
+        self.watch_task = Some(task);
+    }
+
     pub async fn next(&mut self) -> Option<Event> {
         self.event_rx.recv().await
     }
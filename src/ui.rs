@@ -1,23 +1,69 @@
 use ratatui::Frame;
 use crate::app::AppRuntimeInfo;
-use crate::state::{EachFrameImpl, State};
+use crate::state::{timer::TimerSource, EachFrameImpl, State};
 use crate::cli::Args;
+use crate::util::dim_buffer;
 
 use crate::widget::fps::FpsWidget;
+use crate::widget::help::HelpWidget;
+use crate::widget::laps::LapsWidget;
+use crate::widget::pomodoro::PomodoroWidget;
 use crate::widget::{AsWeatherWidget, WeatherWidget};
-use crate::widget::timer::Timer;
+use crate::widget::timer::{color::{Paint, TimerColor}, Timer};
 
-pub fn ui<T: EachFrameImpl + AsWeatherWidget>(f: &mut Frame, state: &mut State<T>, args: Args, runtime_info: AppRuntimeInfo) {
+pub fn ui<T: EachFrameImpl + AsWeatherWidget>(f: &mut Frame, state: &mut State<T>, args: &Args, runtime_info: AppRuntimeInfo) {
     let area = f.area();
+    let timer_color = match &state.source {
+        TimerSource::Pomodoro(pomodoro) => args.phase_color(pomodoro.phase),
+        _ => args.timer_color,
+    };
+    let timer_color = match (&state.burn_in, timer_color) {
+        (Some(burn_in), TimerColor::Solid(color)) => TimerColor::Solid(burn_in.cycle_color(color)),
+        (_, color) => color,
+    };
+    let timer_color = if state.alarm.flash {
+        TimerColor::Solid(args.alarm_color)
+    } else if state.chime.highlight {
+        TimerColor::Solid(args.chime_color)
+    } else {
+        timer_color
+    };
+    let paint = Paint::new(timer_color, args.timer_gradient, state.timer_state.area);
 
     f.render_stateful_widget(WeatherWidget::new(state.weather.as_weather_widget()), area, &mut state.rb);
     f.render_widget(Timer {
         timer: state.timer,
-        color: args.timer_color,
+        color: paint,
         state: &state.timer_state,
+        progress: state.source.minute_fraction(),
     }, area);
 
-    if args.show_fps {
-        f.render_widget(FpsWidget(runtime_info.fps), area)
+    match &state.source {
+        TimerSource::Stopwatch(stopwatch) => f.render_widget(LapsWidget {
+            laps: &stopwatch.laps,
+            color: paint.base(),
+        }, area),
+        TimerSource::Pomodoro(pomodoro) => f.render_widget(PomodoroWidget {
+            pomodoro,
+            color: paint.base(),
+            timer_area: state.timer_state.area,
+        }, area),
+        TimerSource::Wall => (),
+    }
+
+    if args.show_fps || runtime_info.paused {
+        f.render_widget(FpsWidget {
+            fps: runtime_info.fps,
+            paused: runtime_info.paused,
+        }, area)
+    }
+
+    if state.burn_in.as_ref().is_some_and(|burn_in| burn_in.night) {
+        dim_buffer(f.buffer_mut(), args.night_brightness);
+    }
+
+    // readable at night too
+    if runtime_info.help {
+        f.render_widget(HelpWidget { args }, area);
     }
 }
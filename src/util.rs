@@ -1,8 +1,18 @@
 use std::{
+    io::Write,
+    path::PathBuf,
+    process::Stdio,
     thread::sleep,
     time::{Duration, SystemTime, UNIX_EPOCH},
 };
 
+use chrono::Local;
+use ratatui::{
+    buffer::Buffer,
+    style::{Color, Modifier},
+};
+use tokio::process::Command;
+
 pub fn waiting_time_to_sync() {
     let now = SystemTime::now();
     let since_the_epoch = now.duration_since(UNIX_EPOCH).expect("Time went backwards");
@@ -11,3 +21,143 @@ pub fn waiting_time_to_sync() {
     let millis_until_next_second = 1000 - (millis % 1000);
     sleep(Duration::from_millis(millis_until_next_second as u64));
 }
+
+pub fn ring_bell() {
+    let mut stdout = std::io::stdout();
+    let _ = stdout.write_all(b"\x07");
+    let _ = stdout.flush();
+}
+
+/// run user command in background, output is discarded
+pub fn spawn_command<'a>(cmd: &str, envs: impl IntoIterator<Item = (&'a str, String)>) {
+    let _ = Command::new("sh")
+        .arg("-c")
+        .arg(cmd)
+        .envs(envs)
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn();
+}
+
+/// write the screen as plain text to `tenki-<date>-<time>.txt` in the working directory
+pub fn save_screenshot(buf: &Buffer) -> std::io::Result<PathBuf> {
+    let path = PathBuf::from(Local::now().format("tenki-%Y%m%d-%H%M%S.txt").to_string());
+    let area = buf.area;
+    let text = (area.top()..area.bottom())
+        .map(|y| {
+            let line = (area.left()..area.right()).map(|x| buf[(x, y)].symbol()).collect::<String>();
+            format!("{}\n", line.trim_end())
+        })
+        .collect::<String>();
+
+    std::fs::write(&path, text)?;
+    Ok(path)
+}
+
+/// approximate rgb of a color, `None` for the default color of terminal
+pub fn to_rgb(color: Color) -> Option<(u8, u8, u8)> {
+    const ANSI: [(u8, u8, u8); 16] = [
+        (0, 0, 0),
+        (128, 0, 0),
+        (0, 128, 0),
+        (128, 128, 0),
+        (0, 0, 128),
+        (128, 0, 128),
+        (0, 128, 128),
+        (192, 192, 192),
+        (128, 128, 128),
+        (255, 0, 0),
+        (0, 255, 0),
+        (255, 255, 0),
+        (0, 0, 255),
+        (255, 0, 255),
+        (0, 255, 255),
+        (255, 255, 255),
+    ];
+
+    let rgb = match color {
+        Color::Reset => return None,
+        Color::Rgb(r, g, b) => (r, g, b),
+        Color::Indexed(i @ 0..=15) => ANSI[i as usize],
+        Color::Indexed(i @ 16..=231) => {
+            let level = |v: u8| if v == 0 { 0 } else { 55 + v * 40 };
+            let i = i - 16;
+            (level(i / 36), level(i / 6 % 6), level(i % 6))
+        }
+        Color::Indexed(i) => {
+            let v = 8 + (i - 232) * 10;
+            (v, v, v)
+        }
+        Color::Black => ANSI[0],
+        Color::Red => ANSI[1],
+        Color::Green => ANSI[2],
+        Color::Yellow => ANSI[3],
+        Color::Blue => ANSI[4],
+        Color::Magenta => ANSI[5],
+        Color::Cyan => ANSI[6],
+        Color::Gray => ANSI[7],
+        Color::DarkGray => ANSI[8],
+        Color::LightRed => ANSI[9],
+        Color::LightGreen => ANSI[10],
+        Color::LightYellow => ANSI[11],
+        Color::LightBlue => ANSI[12],
+        Color::LightMagenta => ANSI[13],
+        Color::LightCyan => ANSI[14],
+        Color::White => ANSI[15],
+    };
+
+    Some(rgb)
+}
+
+/// hue in degrees, saturation and value in `0.0..=1.0`
+pub fn to_hsv((r, g, b): (u8, u8, u8)) -> (f32, f32, f32) {
+    let (r, g, b) = (r as f32 / 255.0, g as f32 / 255.0, b as f32 / 255.0);
+    let max = r.max(g).max(b);
+    let delta = max - r.min(g).min(b);
+
+    let h = if delta == 0.0 {
+        0.0
+    } else if max == r {
+        60.0 * ((g - b) / delta).rem_euclid(6.0)
+    } else if max == g {
+        60.0 * ((b - r) / delta + 2.0)
+    } else {
+        60.0 * ((r - g) / delta + 4.0)
+    };
+    let s = if max == 0.0 { 0.0 } else { delta / max };
+
+    (h, s, max)
+}
+
+pub fn from_hsv(h: f32, s: f32, v: f32) -> Color {
+    let c = v * s;
+    let x = c * (1.0 - ((h / 60.0).rem_euclid(2.0) - 1.0).abs());
+    let (r, g, b) = match h.rem_euclid(360.0) as u16 / 60 {
+        0 => (c, x, 0.0),
+        1 => (x, c, 0.0),
+        2 => (0.0, c, x),
+        3 => (0.0, x, c),
+        4 => (x, 0.0, c),
+        _ => (c, 0.0, x),
+    };
+    let m = v - c;
+    let to_u8 = |v: f32| ((v + m) * 255.0).round() as u8;
+
+    Color::Rgb(to_u8(r), to_u8(g), to_u8(b))
+}
+
+/// scale brightness of color, the default color of terminal gets dim modifier instead
+pub fn dim_buffer(buf: &mut Buffer, brightness: f32) {
+    for cell in buf.content.iter_mut() {
+        match to_rgb(cell.fg) {
+            Some(rgb) => {
+                let (h, s, v) = to_hsv(rgb);
+                cell.fg = from_hsv(h, s, v * brightness);
+            }
+            None => {
+                cell.modifier.insert(Modifier::DIM);
+            }
+        }
+    }
+}
@@ -1,27 +1,26 @@
 use crate::{
     cli::Args,
     state::{buffer::RenderBuffer, dropping::DroppingState, tail::TailState, wind::WindState, EachFrameImpl, Mode, ShouldRender},
-    widget::{weather::GeneralWeatherWidget, AsWeatherWidget},
+    widget::{weather::{GeneralWeatherWidget, WeatherStyle}, AsWeatherWidget},
 };
 
 use super::WeatherImpl;
 
-const DEF_LEVEL: u16 = 50;
-const DEF_TAIL_LEVEL: u16 = 500;
-
 pub struct GeneralDropping {
     wind: WindState,
     dropping: DroppingState,
+    style: WeatherStyle,
 }
 
 impl GeneralDropping {
-    pub fn new(args: Args) -> Self {
+    pub fn new(args: &Args) -> Self {
         Self {
             wind: WindState::new(args.wind),
             dropping: DroppingState {
-                threshold: args.level.unwrap_or(DEF_LEVEL),
+                threshold: args.level.unwrap_or(args.mode.default_level()),
                 mode: args.mode,
             },
+            style: args.style(args.mode),
         }
     }
 }
@@ -41,8 +40,8 @@ impl AsWeatherWidget for GeneralDropping {
     fn as_weather_widget(&self) -> Self::Weather {
         use Mode::*;
         match self.dropping.mode {
-            Rain => GeneralWeatherWidget::Rain(self.wind.direction),
-            Snow => GeneralWeatherWidget::Snow,
+            Rain => GeneralWeatherWidget::Rain(self.wind.direction, self.style),
+            Snow => GeneralWeatherWidget::Snow(self.style),
             _ => panic!("has not been implemented yet"),
         }
     }
@@ -52,17 +51,19 @@ pub struct TailDropping {
     wind: WindState,
     dropping: DroppingState,
     tail: TailState,
+    style: WeatherStyle,
 }
 
 impl TailDropping {
-    pub fn new(args: Args) -> Self {
+    pub fn new(args: &Args) -> Self {
         Self {
             wind: WindState::new(args.wind.without_random()),
             tail: TailState::new(args.wind.into()),
             dropping: DroppingState {
-                threshold: args.level.unwrap_or(DEF_TAIL_LEVEL),
+                threshold: args.level.unwrap_or(args.mode.default_level()),
                 mode: args.mode,
             },
+            style: args.style(args.mode),
         }
     }
 }
@@ -83,7 +84,7 @@ impl AsWeatherWidget for TailDropping {
     fn as_weather_widget(&self) -> Self::Weather {
         use Mode::*;
         match self.dropping.mode {
-            Meteor => GeneralWeatherWidget::Meteor(self.tail.mode),
+            Meteor => GeneralWeatherWidget::Meteor(self.tail.mode, self.style),
             _ => panic!("has not been implemented yet"),
         }
     }
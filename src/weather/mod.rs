@@ -11,8 +11,8 @@ pub trait WeatherImpl: EachFrameImpl + AsWeatherWidget<Weather=GeneralWeatherWid
 
 pub struct Weather(Box<dyn WeatherImpl>);
 
-impl Weather {
-    pub fn from(args: Args) -> impl EachFrameImpl + AsWeatherWidget {
+impl From<&Args> for Weather {
+    fn from(args: &Args) -> Self {
         use Mode::*;
         match args.mode {
             Rain | Snow => Self(Box::new(GeneralDropping::new(args))),
@@ -5,20 +5,30 @@ use ratatui::{
     widgets::Widget,
 };
 
-pub struct FpsWidget(pub usize);
+/// fps, or paused in place of it
+pub struct FpsWidget {
+    pub fps: usize,
+    pub paused: bool,
+}
 
 impl Widget for FpsWidget {
     fn render(self, area: ratatui::prelude::Rect, buf: &mut ratatui::prelude::Buffer) {
+        let line = if self.paused {
+            Line::styled("paused", Style::new().yellow())
+        } else {
+            Line::styled(self.fps.to_string(), Style::new().green())
+        };
+
         let [_, fps_area, _] = Layout::new(
             Direction::Horizontal,
             [
                 Constraint::Min(0),
-                Constraint::Length(3),
+                Constraint::Length(line.width().max(3) as u16),
                 Constraint::Length(2),
             ],
         )
         .areas(area);
 
-        Line::styled(self.0.to_string(), Style::new().green()).render(fps_area, buf);
+        line.render(fps_area, buf);
     }
 }
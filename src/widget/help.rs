@@ -0,0 +1,61 @@
+use clap::ValueEnum;
+use ratatui::{
+    buffer::Buffer,
+    layout::{Constraint, Flex, Layout, Rect},
+    style::Stylize,
+    text::Line,
+    widgets::{Block, Clear, Paragraph, Widget},
+};
+
+use crate::{app::Action, cli::Args};
+
+/// width of names of actions and settings
+const NAME_WIDTH: usize = 16;
+
+/// popup in the center listing keys of actions and current settings
+pub struct HelpWidget<'a> {
+    pub args: &'a Args,
+}
+
+impl<'a> HelpWidget<'a> {
+    fn line(name: &str, value: String) -> Line<'static> {
+        Line::from(vec![format!(" {name:<NAME_WIDTH$} ").bold(), format!("{value} ").into()])
+    }
+
+    fn lines(&self) -> Vec<Line<'static>> {
+        let args = self.args;
+        let keys = Action::value_variants().iter().filter_map(|action| {
+            let name = action.to_possible_value()?.get_name().to_string();
+            let chords = args.keys.chords(*action).map(|chord| chord.to_string()).collect::<Vec<_>>();
+            Some(Self::line(&name, chords.join(", ")))
+        });
+
+        let settings = [
+            ("mode", args.mode.to_string()),
+            ("level", args.level.unwrap_or(args.mode.default_level()).to_string()),
+            ("wind", args.wind.to_string()),
+            ("fps / tps", format!("{} / {}", args.fps, args.tps)),
+            ("speed", format!("{}x", args.speed)),
+            ("timer mode", args.timer_mode.map_or("still".to_string(), |mode| mode.to_string())),
+        ]
+        .map(|(name, value)| Self::line(name, value));
+
+        keys.chain([Line::default()]).chain(settings).collect()
+    }
+}
+
+impl<'a> Widget for HelpWidget<'a> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let lines = self.lines();
+        let width = lines.iter().map(Line::width).max().unwrap_or_default() as u16 + 2;
+        let height = lines.len() as u16 + 2;
+
+        let [popup] = Layout::horizontal([Constraint::Length(width)]).flex(Flex::Center).areas(area);
+        let [popup] = Layout::vertical([Constraint::Length(height)]).flex(Flex::Center).areas(popup);
+
+        Clear.render(popup, buf);
+        Paragraph::new(lines)
+            .block(Block::bordered().title(" Help "))
+            .render(popup, buf);
+    }
+}
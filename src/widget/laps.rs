@@ -0,0 +1,70 @@
+use std::time::Duration;
+
+use ratatui::{
+    buffer::Buffer,
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Style},
+    text::Line,
+    widgets::{Block, Clear, Paragraph, Widget},
+};
+
+const LAPS_LAYOUT_WIDTH: u16 = 28;
+
+/// side panel listing stopwatch laps, the latest lap on top
+pub struct LapsWidget<'a> {
+    pub laps: &'a [Duration],
+    pub color: Color,
+}
+
+impl<'a> LapsWidget<'a> {
+    fn format_duration(d: Duration) -> String {
+        let secs = d.as_secs();
+        format!(
+            "{:02}:{:02}:{:02}.{}",
+            secs / 3600 % 100,
+            secs / 60 % 60,
+            secs % 60,
+            d.subsec_millis() / 100
+        )
+    }
+}
+
+impl<'a> Widget for LapsWidget<'a> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        if self.laps.is_empty() {
+            return;
+        }
+
+        let height = (self.laps.len() as u16).saturating_add(2).min(area.height);
+        let [_, panel, _] = Layout::new(
+            Direction::Horizontal,
+            [
+                Constraint::Min(0),
+                Constraint::Length(LAPS_LAYOUT_WIDTH),
+                Constraint::Length(2),
+            ],
+        )
+        .areas(area);
+        let [_, panel] = Layout::new(
+            Direction::Vertical,
+            [Constraint::Length(1), Constraint::Length(height)],
+        )
+        .areas(panel);
+
+        let lines = self.laps
+            .iter()
+            .enumerate()
+            .rev()
+            .map(|(i, lap)| {
+                let split = lap.saturating_sub(i.checked_sub(1).map_or(Duration::ZERO, |prev| self.laps[prev]));
+                Line::from(format!("{:>2} {} +{}", i + 1, Self::format_duration(*lap), Self::format_duration(split)))
+            })
+            .collect::<Vec<_>>();
+
+        Clear.render(panel, buf);
+        Paragraph::new(lines)
+            .style(Style::new().fg(self.color))
+            .block(Block::bordered().title(" Laps "))
+            .render(panel, buf);
+    }
+}
@@ -5,15 +5,18 @@ use ratatui::{
     widgets::StatefulWidget,
 };
 
-use crate::state::{buffer::RenderBuffer, Cell, CellType};
+use crate::state::{buffer::RenderBuffer, Cell, CellType, Particle};
 
 pub mod fps;
+pub mod help;
+pub mod laps;
+pub mod pomodoro;
 pub mod timer;
 pub mod weather;
 
 pub trait WeatherWidgetImpl {
-    fn get_char(&self, _: CellType) -> char;
-    fn get_render_cell_type(&self, cell: &Cell) -> CellType;
+    fn get_char(&self, _: Particle) -> char;
+    fn get_render_particle(&self, cell: &Cell) -> Particle;
     fn get_color(&self, cell: CellType) -> Color;
 
     fn render_background(&self, area: Rect, buf: &mut Buffer, rb: &RenderBuffer) {
@@ -25,10 +28,10 @@ pub trait WeatherWidgetImpl {
             let column = column.borrow();
             for y in area.top()..area.bottom() {
                 if let Some(cell) = column.get(y as usize) {
-                    let cell_type = self.get_render_cell_type(cell);
+                    let particle = self.get_render_particle(cell);
                     buf[(x, y)]
-                        .set_char(self.get_char(cell_type))
-                        .set_fg(self.get_color(cell_type));
+                        .set_char(self.get_char(particle))
+                        .set_fg(self.get_color(particle.kind));
                 }
             }
         }
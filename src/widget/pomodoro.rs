@@ -0,0 +1,44 @@
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    style::{Color, Style},
+    text::Line,
+    widgets::Widget,
+};
+
+use crate::state::pomodoro::Pomodoro;
+
+/// phase and cycle counter shown under the timer
+pub struct PomodoroWidget<'a> {
+    pub pomodoro: &'a Pomodoro,
+    pub color: Color,
+    pub timer_area: Rect,
+}
+
+impl<'a> Widget for PomodoroWidget<'a> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let interval = self.pomodoro.long_break_interval();
+        let mut text = format!(
+            "{} #{} ({}/{})",
+            self.pomodoro.phase,
+            self.pomodoro.cycle,
+            (self.pomodoro.cycle - 1) % interval + 1,
+            interval,
+        );
+
+        if self.pomodoro.is_paused() {
+            text.push_str(" paused");
+        }
+
+        let line_area = Rect {
+            y: self.timer_area.bottom().saturating_add(1),
+            height: 1,
+            ..self.timer_area
+        }
+        .intersection(area);
+
+        Line::styled(text, Style::new().fg(self.color))
+            .centered()
+            .render(line_area, buf);
+    }
+}
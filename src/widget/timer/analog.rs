@@ -0,0 +1,77 @@
+use std::f64::consts::TAU;
+
+use ratatui::{
+    buffer::Buffer,
+    layout::Size,
+    symbols::Marker,
+    widgets::{
+        canvas::{Canvas, Circle, Line},
+        Widget,
+    },
+};
+
+use super::{Timer, TimerFace};
+
+/// the face takes 2/3 of the shorter side of screen
+const FACE_SCALE: (u16, u16) = (2, 3);
+const MIN_FACE_HEIGHT: u16 = 8;
+
+/// round clock face plotted with braille dots, a cell holds 2x4 dots so the face is twice as wide as high
+pub struct AnalogFace;
+
+impl AnalogFace {
+    /// unit vector of a hand, `turn` is the fraction of a full turn from 12 o'clock
+    fn hand(turn: f64, length: f64) -> (f64, f64) {
+        let angle = turn * TAU;
+        (angle.sin() * length, angle.cos() * length)
+    }
+}
+
+impl TimerFace for AnalogFace {
+    fn layout(&self, boundary: Size) -> Size {
+        let (num, den) = FACE_SCALE;
+        let fit = boundary.height.min(boundary.width / 2);
+        // keep a readable face on small screens but never beyond the boundary
+        let height = (fit * num / den).max(MIN_FACE_HEIGHT).min(fit);
+
+        Size {
+            width: height * 2,
+            height,
+        }
+    }
+
+    fn render(&self, timer: &Timer, buf: &mut Buffer) {
+        let area = timer.state.face_area().intersection(buf.area);
+        let color = timer.color.base();
+        let t = timer.timer;
+
+        let seconds = t.seconds as f64 + t.tenths.unwrap_or(0) as f64 / 10.;
+        let minutes = t.minutes as f64 + seconds / 60.;
+        let hours = (t.hours % 12) as f64 + minutes / 60.;
+
+        let hands = [
+            Self::hand(hours / 12., 0.5),
+            Self::hand(minutes / 60., 0.75),
+            Self::hand(seconds / 60., 0.9),
+        ];
+
+        Canvas::default()
+            .marker(Marker::Braille)
+            .x_bounds([-1., 1.])
+            .y_bounds([-1., 1.])
+            .paint(|ctx| {
+                ctx.draw(&Circle::new(0., 0., 1., color));
+
+                for hour in 0..12 {
+                    let (x1, y1) = Self::hand(hour as f64 / 12., 0.85);
+                    let (x2, y2) = Self::hand(hour as f64 / 12., 1.);
+                    ctx.draw(&Line::new(x1, y1, x2, y2, color));
+                }
+
+                for (x, y) in hands {
+                    ctx.draw(&Line::new(0., 0., x, y, color));
+                }
+            })
+            .render(area, buf);
+    }
+}
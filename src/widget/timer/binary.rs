@@ -0,0 +1,99 @@
+use ratatui::{
+    buffer::Buffer,
+    layout::{Rect, Size},
+    style::Modifier,
+};
+
+use super::{color::Paint, Timer, TimerFace};
+
+const LIT_CHAR: char = '█';
+const UNLIT_CHAR: char = '░';
+
+const BLOCK_WIDTH: u16 = 4;
+const BLOCK_HEIGHT: u16 = 2;
+const ROW_GAP: u16 = 1;
+const COLUMN_GAP: u16 = 2;
+const GROUP_GAP: u16 = 4;
+
+/// time as columns of lit/unlit blocks, least significant bit at the bottom
+pub struct BinaryFace {
+    /// a column for each decimal digit instead of each of hours, minutes and seconds
+    pub bcd: bool,
+}
+
+impl BinaryFace {
+    /// bits of each column in groups of hours, minutes and seconds
+    fn columns(&self) -> [&'static [u8]; 3] {
+        if self.bcd {
+            [&[2, 4], &[3, 4], &[3, 4]]
+        } else {
+            [&[5], &[6], &[6]]
+        }
+    }
+
+    fn values(&self, d: u8) -> [u8; 2] {
+        if self.bcd {
+            [d / 10, d % 10]
+        } else {
+            [d, 0]
+        }
+    }
+
+    fn rows(&self) -> u16 {
+        if self.bcd { 4 } else { 6 }
+    }
+
+    fn render_block(area: Rect, lit: bool, paint: &Paint, buf: &mut Buffer) {
+        let area = area.intersection(buf.area);
+        for y in area.top()..area.bottom() {
+            for x in area.left()..area.right() {
+                let cell = &mut buf[(x, y)];
+                if lit {
+                    cell.set_char(LIT_CHAR).set_fg(paint.at(x, y));
+                } else {
+                    cell.set_char(UNLIT_CHAR).set_fg(paint.at(x, y)).modifier.insert(Modifier::DIM);
+                }
+            }
+        }
+    }
+}
+
+impl TimerFace for BinaryFace {
+    fn layout(&self, _: Size) -> Size {
+        let columns = self.columns().iter().map(|group| group.len() as u16).sum::<u16>();
+        let groups = self.columns().len() as u16;
+        let rows = self.rows();
+
+        Size {
+            width: columns * BLOCK_WIDTH + (columns - groups) * COLUMN_GAP + (groups - 1) * GROUP_GAP,
+            height: rows * BLOCK_HEIGHT + (rows - 1) * ROW_GAP,
+        }
+    }
+
+    fn render(&self, timer: &Timer, buf: &mut Buffer) {
+        let area = timer.state.face_area();
+        let rows = self.rows();
+        let t = timer.timer;
+
+        let mut x = area.left();
+        for (group, d) in self.columns().into_iter().zip([t.hours, t.minutes, t.seconds]) {
+            for (bits, value) in group.iter().zip(self.values(d)) {
+                for bit in 0..*bits as u16 {
+                    let row = rows - 1 - bit;
+                    let block = Rect {
+                        x,
+                        y: area.top() + row * (BLOCK_HEIGHT + ROW_GAP),
+                        width: BLOCK_WIDTH,
+                        height: BLOCK_HEIGHT,
+                    };
+
+                    Self::render_block(block, value & (1 << bit) != 0, &timer.color, buf);
+                }
+
+                x += BLOCK_WIDTH + COLUMN_GAP;
+            }
+
+            x += GROUP_GAP - COLUMN_GAP;
+        }
+    }
+}
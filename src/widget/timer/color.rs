@@ -0,0 +1,131 @@
+use std::{f32::consts::TAU, fmt::Display, str::FromStr, time::SystemTime};
+
+use chrono::{Local, Timelike};
+use clap::ValueEnum;
+use ratatui::{layout::Rect, style::Color};
+
+use crate::util::{from_hsv, to_rgb};
+
+/// seconds of a round of rainbow hue
+const RAINBOW_CYCLE: u64 = 60;
+/// color of color-by-time at midnight
+const NIGHT_COLOR: (u8, u8, u8) = (90, 140, 255);
+/// color of color-by-time at noon
+const NOON_COLOR: (u8, u8, u8) = (255, 170, 60);
+
+/// color of the timer, parsed from a color, `rainbow`, `time` or a gradient `<color>..<color>`
+#[derive(Copy, Clone)]
+pub enum TimerColor {
+    Solid(Color),
+    /// hue across the timer cycling over time
+    Rainbow,
+    Gradient(Color, Color),
+    /// cooler at night, warmer at noon
+    Time,
+}
+
+impl FromStr for TimerColor {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let parse = |s: &str| Color::from_str(s.trim()).map_err(|_| format!("invalid color `{s}`"));
+
+        match s {
+            "rainbow" => Ok(Self::Rainbow),
+            "time" => Ok(Self::Time),
+            s => match s.split_once("..") {
+                Some((from, to)) => Ok(Self::Gradient(parse(from)?, parse(to)?)),
+                None => parse(s).map(Self::Solid),
+            },
+        }
+    }
+}
+
+/// direction of gradient
+#[derive(Copy, Clone, Default, ValueEnum)]
+pub enum GradientAxis {
+    /// from left to right across digits
+    #[default]
+    Columns,
+    /// from top to bottom across rows
+    Rows,
+}
+
+impl Display for GradientAxis {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = match *self {
+            GradientAxis::Columns => "columns",
+            GradientAxis::Rows => "rows",
+        };
+
+        s.fmt(f)
+    }
+}
+
+/// timer color resolved for a frame, gives the color of each cell in the timer area
+#[derive(Copy, Clone)]
+pub struct Paint {
+    color: TimerColor,
+    axis: GradientAxis,
+    area: Rect,
+    /// hue of rainbow at the left of area
+    hue: f32,
+    /// 0 at midnight, 1 at noon
+    daylight: f32,
+}
+
+impl Paint {
+    pub fn new(color: TimerColor, axis: GradientAxis, area: Rect) -> Self {
+        let secs = SystemTime::now()
+            .duration_since(SystemTime::UNIX_EPOCH)
+            .map_or(0, |d| d.as_secs());
+        let now = Local::now();
+        let hour = now.hour() as f32 + now.minute() as f32 / 60.;
+
+        Self {
+            color,
+            axis,
+            area,
+            hue: (secs % RAINBOW_CYCLE) as f32 / RAINBOW_CYCLE as f32 * 360.,
+            daylight: (1. - (hour / 24. * TAU).cos()) / 2.,
+        }
+    }
+
+    /// color of the cell at `(x, y)` of screen
+    pub fn at(&self, x: u16, y: u16) -> Color {
+        match self.color {
+            TimerColor::Solid(color) => color,
+            TimerColor::Rainbow => from_hsv(self.hue + self.fraction(x, y, GradientAxis::Columns) * 360., 1., 1.),
+            TimerColor::Gradient(from, to) => Self::mix(
+                to_rgb(from).unwrap_or((255, 255, 255)),
+                to_rgb(to).unwrap_or((255, 255, 255)),
+                self.fraction(x, y, self.axis),
+            ),
+            TimerColor::Time => Self::mix(NIGHT_COLOR, NOON_COLOR, self.daylight),
+        }
+    }
+
+    /// color of the center, for faces and widgets drawn in a single color
+    pub fn base(&self) -> Color {
+        self.at(self.area.x + self.area.width / 2, self.area.y + self.area.height / 2)
+    }
+
+    /// position of cell in area along the axis, `0.0..=1.0`
+    fn fraction(&self, x: u16, y: u16, axis: GradientAxis) -> f32 {
+        let (v, start, len) = match axis {
+            GradientAxis::Columns => (x, self.area.x, self.area.width),
+            GradientAxis::Rows => (y, self.area.y, self.area.height),
+        };
+
+        if len <= 1 {
+            return 0.;
+        }
+
+        (v.saturating_sub(start) as f32 / (len - 1) as f32).min(1.)
+    }
+
+    fn mix(from: (u8, u8, u8), to: (u8, u8, u8), t: f32) -> Color {
+        let mix = |a: u8, b: u8| (a as f32 + (b as f32 - a as f32) * t).round() as u8;
+        Color::Rgb(mix(from.0, to.0), mix(from.1, to.1), mix(from.2, to.2))
+    }
+}
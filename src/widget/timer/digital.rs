@@ -0,0 +1,248 @@
+use ratatui::{
+    buffer::Buffer,
+    layout::{Constraint, Direction, Layout, Rect, Size},
+    style::{Modifier, Style},
+    text::Line,
+    widgets::Widget,
+};
+
+use crate::theme::default_timer_glyphs;
+
+use super::{
+    color::Paint,
+    font::{Font, Glyph},
+    pixel::{PixelGrid, PixelMode},
+    Timer, TimerFace,
+};
+
+/// lit cell of text fonts, drawn with the block glyph of the theme
+pub const TIMER_CHAR: char = '█';
+
+/// space between two digits of a number
+const DIGIT_GAP: u16 = 1;
+/// space on each side of colon
+const COLON_PADDING: u16 = 1;
+/// trailing field for tenth of second, e.g. ` .7`
+const TENTHS_LAYOUT_WIDTH: u16 = 3;
+/// `HH:MM:SS`, used when even the compact font does not fit the screen
+const TEXT_LAYOUT_WIDTH: u16 = 8;
+
+/// chars of lit cells and of the upper half block ending colon dots in block pixel mode
+#[derive(Copy, Clone)]
+pub struct TimerGlyphs {
+    pub block: char,
+    pub colon: char,
+}
+
+/// glyphs of the bundled default theme
+impl Default for TimerGlyphs {
+    fn default() -> Self {
+        default_timer_glyphs()
+    }
+}
+
+#[derive(Copy, Clone)]
+pub enum Scale {
+    /// the largest scale taking no more than a fraction of the screen
+    Auto,
+    Fixed(u16),
+}
+
+/// how the timer fits the screen
+enum Fit<'a> {
+    Glyph(&'a Font, u16),
+    Text,
+}
+
+/// `HH:MM:SS` drawn with glyphs of a font. glyphs are rasterised into virtual pixels then packed into
+/// cells, the layout is derived from the font size, scale and pixels per cell
+pub struct DigitalFace {
+    pub font: Font,
+    /// fallback font for small screen
+    pub compact: Font,
+    pub pixel: PixelMode,
+    pub scale: Scale,
+    /// fraction of the screen for auto scale
+    pub fraction: f32,
+    pub with_tenths: bool,
+    pub glyphs: TimerGlyphs,
+}
+
+impl DigitalFace {
+    /// cells taken by a glyph
+    fn glyph_size(&self, font: &Font, width: u16, scale: u16) -> (u16, u16) {
+        self.pixel.cells(width * scale, font.height * scale)
+    }
+
+    fn timer_layout(&self, font: &Font, scale: u16) -> [u16; 5] {
+        let (digit, _) = self.glyph_size(font, font.width, scale);
+        let number = digit * 2 + DIGIT_GAP * scale;
+        let colon = font.colon().map_or(self.glyph_size(font, 1, scale).0, |colon| self.glyph_size(font, colon.width, scale).0) + COLON_PADDING * 2 * scale;
+        [number, colon, number, colon, number]
+    }
+
+    fn tenths_width(&self) -> u16 {
+        if self.with_tenths { TENTHS_LAYOUT_WIDTH } else { 0 }
+    }
+
+    fn size(&self, fit: &Fit) -> Size {
+        match fit {
+            Fit::Glyph(font, scale) => Size {
+                width: self.timer_layout(font, *scale).iter().sum::<u16>() + self.tenths_width(),
+                height: self.glyph_size(font, font.width, *scale).1,
+            },
+            Fit::Text => Size {
+                width: TEXT_LAYOUT_WIDTH + self.tenths_width().saturating_sub(1),
+                height: 1,
+            },
+        }
+    }
+
+    fn fits(&self, fit: &Fit, boundary: Size) -> bool {
+        let size = self.size(fit);
+        size.width <= boundary.width && size.height <= boundary.height
+    }
+
+    /// choose scale of the font, then fall back to compact font and plain text if it does not fit
+    fn fit(&self, boundary: Size) -> Fit<'_> {
+        let scale = match self.scale {
+            Scale::Fixed(scale) => scale,
+            Scale::Auto => {
+                let limit = Size {
+                    width: (boundary.width as f32 * self.fraction) as u16,
+                    height: (boundary.height as f32 * self.fraction) as u16,
+                };
+
+                (2..)
+                    .take_while(|scale| self.fits(&Fit::Glyph(&self.font, *scale), limit))
+                    .last()
+                    .unwrap_or(1)
+            }
+        };
+
+        (1..=scale)
+            .rev()
+            .map(|scale| Fit::Glyph(&self.font, scale))
+            .chain([Fit::Glyph(&self.compact, 1)])
+            .find(|fit| self.fits(fit, boundary))
+            .unwrap_or(Fit::Text)
+    }
+
+    fn render_colon(&self, font: &Font, scale: u16, area: Rect, paint: &Paint, buf: &mut Buffer) {
+        let left = area.left() + COLON_PADDING * scale;
+
+        match font.colon() {
+            Some(colon) => self.render_glyph(colon, font.height, scale, Rect { x: left, ..area }, paint, buf),
+            None => {
+                // dots a quarter of the glyph from its top and bottom, in rows of the unscaled font
+                let mut pixels = PixelGrid::new(scale, font.height * scale);
+                for row in [font.height / 4, font.height - 1 - font.height / 4] {
+                    self.set_dot(&mut pixels, row * scale, scale);
+                }
+
+                pixels.render(self.pixel, left, area.top(), paint, buf);
+            }
+        }
+    }
+
+    /// a block pixel is twice as high as wide, so the dot is filled by half of the rows and ends with an upper half
+    /// block. pixels of half and braille modes are square
+    fn set_dot(&self, pixels: &mut PixelGrid, top: u16, scale: u16) {
+        let block = matches!(self.pixel, PixelMode::Block);
+        let rows = if block { scale.div_ceil(2) } else { scale };
+        for y in 0..rows {
+            let c = if block && y == scale / 2 { self.glyphs.colon } else { self.glyphs.block };
+            for x in 0..scale {
+                pixels.set(x, top + y, c);
+            }
+        }
+    }
+
+    fn render_decimal(&self, font: &Font, scale: u16, d: u8, area: Rect, paint: &Paint, buf: &mut Buffer) {
+        let (digit, _) = self.glyph_size(font, font.width, scale);
+        let layout = Layout::new(
+            Direction::Horizontal,
+            Constraint::from_lengths([digit, DIGIT_GAP * scale, digit]),
+        )
+        .split(area);
+
+        self.render_number(font, scale, d / 10, layout[0], buf, paint);
+        self.render_number(font, scale, d % 10, layout[2], buf, paint);
+    }
+
+    fn render_tenths(tenths: u8, area: Rect, paint: &Paint, buf: &mut Buffer) {
+        let area = Rect {
+            y: area.bottom().saturating_sub(1),
+            height: 1,
+            ..area
+        };
+
+        let color = paint.at(area.x, area.y);
+        Line::styled(format!(" .{tenths}"), Style::new().fg(color).bold()).render(area.intersection(buf.area), buf);
+    }
+
+    fn render_number(&self, font: &Font, scale: u16, number: u8, area: Rect, buf: &mut Buffer, paint: &Paint) {
+        self.render_glyph(font.digit(number), font.height, scale, area, paint, buf);
+    }
+
+    /// rasterise scaled glyph into pixels, then pack them into cells from the left top of area
+    fn render_glyph(&self, glyph: &Glyph, height: u16, scale: u16, area: Rect, paint: &Paint, buf: &mut Buffer) {
+        let mut pixels = PixelGrid::new(glyph.width * scale, height * scale);
+        for y in 0..height * scale {
+            for x in 0..glyph.width * scale {
+                if let Some(c) = glyph.get(x / scale, y / scale) {
+                    pixels.set(x, y, if c == TIMER_CHAR { self.glyphs.block } else { c });
+                }
+            }
+        }
+
+        pixels.render(self.pixel, area.left(), area.top(), paint, buf);
+    }
+
+    fn render_text(timer: &Timer, area: Rect, buf: &mut Buffer) {
+        let t = timer.timer;
+        let colon = if timer.state.colon.show { ':' } else { ' ' };
+        let mut text = format!("{:02}{colon}{:02}{colon}{:02}", t.hours, t.minutes, t.seconds);
+        if let Some(tenths) = t.tenths {
+            text.push_str(&format!(".{tenths}"));
+        }
+
+        for (i, c) in text.chars().enumerate() {
+            let x = area.x + i as u16;
+            if let Some(cell) = buf.cell_mut((x, area.y)) {
+                cell.set_char(c).set_fg(timer.color.at(x, area.y)).modifier.insert(Modifier::BOLD);
+            }
+        }
+    }
+}
+
+impl TimerFace for DigitalFace {
+    fn layout(&self, boundary: Size) -> Size {
+        self.size(&self.fit(boundary))
+    }
+
+    fn render(&self, timer: &Timer, buf: &mut Buffer) {
+        let Fit::Glyph(font, scale) = self.fit(timer.state.face_boundary()) else {
+            return Self::render_text(timer, timer.state.face_area(), buf);
+        };
+
+        let [hours, colon_left, minutes, colon_right, seconds, tenths] = Layout::new(
+            Direction::Horizontal,
+            Constraint::from_lengths(self.timer_layout(font, scale)).into_iter().chain([Constraint::Min(0)]),
+        )
+        .areas(timer.state.face_area());
+
+        self.render_decimal(font, scale, timer.timer.hours, hours, &timer.color, buf);
+        self.render_decimal(font, scale, timer.timer.minutes, minutes, &timer.color, buf);
+        self.render_decimal(font, scale, timer.timer.seconds, seconds, &timer.color, buf);
+
+        if let Some(t) = timer.timer.tenths {
+            Self::render_tenths(t, tenths, &timer.color, buf);
+        }
+
+        if timer.state.colon.show {
+            self.render_colon(font, scale, colon_left, &timer.color, buf);
+            self.render_colon(font, scale, colon_right, &timer.color, buf);
+        }
+    }
+}
@@ -0,0 +1,211 @@
+use std::{fmt::Display, path::Path};
+
+use anyhow::{anyhow, bail, Context, Result};
+
+use super::digital::TIMER_CHAR;
+
+/// `round` and `smooth` are high resolution fonts for half block and braille pixels
+const BUNDLED_FONTS: [(&str, &str); 7] = [
+    ("block", include_str!("fonts/block.txt")),
+    ("thin", include_str!("fonts/thin.txt")),
+    ("wide", include_str!("fonts/wide.txt")),
+    ("seven-segment", include_str!("fonts/seven-segment.txt")),
+    ("small", include_str!("fonts/small.txt")),
+    ("round", include_str!("fonts/round.txt")),
+    ("smooth", include_str!("fonts/smooth.txt")),
+];
+
+pub const DEFAULT_FONT: &str = "block";
+/// fallback font when the timer does not fit the screen
+pub const COMPACT_FONT: &str = "small";
+
+pub struct Glyph {
+    pub width: u16,
+    /// row-major cells, `None` is an unlit cell
+    cells: Vec<Option<char>>,
+}
+
+impl Glyph {
+    pub fn get(&self, x: u16, y: u16) -> Option<char> {
+        self.cells.get(y as usize * self.width as usize + x as usize).copied().flatten()
+    }
+
+    fn from_rows(rows: &[Vec<Option<char>>]) -> Self {
+        let width = rows.iter().map(Vec::len).max().unwrap_or(0);
+        let cells = rows
+            .iter()
+            .flat_map(|row| row.iter().copied().chain(std::iter::repeat(None)).take(width))
+            .collect();
+
+        Self {
+            width: width as u16,
+            cells,
+        }
+    }
+}
+
+/// glyphs of digits and an optional colon
+pub struct Font {
+    pub width: u16,
+    pub height: u16,
+    digits: Vec<Glyph>,
+    colon: Option<Glyph>,
+}
+
+impl Font {
+    /// load bundled font by name, or a font file. `.flf` files are parsed as FIGlet fonts
+    pub fn load(name: &str) -> Result<Self> {
+        if let Some((_, text)) = BUNDLED_FONTS.iter().find(|(n, _)| *n == name) {
+            return Self::parse_text(text);
+        }
+
+        let path = Path::new(name);
+        let text = std::fs::read_to_string(path)
+            .with_context(|| format!("can not read font `{name}`, bundled fonts are {}", BundledFonts))?;
+
+        let font = if path.extension().is_some_and(|ext| ext == "flf") {
+            Self::parse_figlet(&text)
+        } else {
+            Self::parse_text(&text)
+        };
+
+        font.with_context(|| format!("invalid font file `{name}`"))
+    }
+
+    pub fn digit(&self, d: u8) -> &Glyph {
+        &self.digits[d as usize % 10]
+    }
+
+    pub fn colon(&self) -> Option<&Glyph> {
+        self.colon.as_ref()
+    }
+
+    /// glyphs of 0 to 9 and an optional colon separated by blank lines, `#` marks a lit cell
+    fn parse_text(text: &str) -> Result<Self> {
+        let glyphs = text
+            .lines()
+            .map(str::trim_end)
+            .collect::<Vec<_>>()
+            .split(|line| line.is_empty())
+            .filter(|rows| !rows.is_empty())
+            .map(|rows| {
+                let rows = rows
+                    .iter()
+                    .map(|row| row.chars().map(|c| (c == '#').then_some(TIMER_CHAR)).collect())
+                    .collect::<Vec<_>>();
+
+                Glyph::from_rows(&rows)
+            })
+            .collect();
+
+        Self::from_glyphs(glyphs)
+    }
+
+    /// FIGlet font, only digits and colon are taken
+    fn parse_figlet(text: &str) -> Result<Self> {
+        let mut lines = text.lines();
+        let header = lines.next().ok_or(anyhow!("empty file"))?;
+        let mut params = header
+            .strip_prefix("flf2a")
+            .ok_or(anyhow!("missing flf2a signature"))?
+            .chars();
+        let hardblank = params.next().ok_or(anyhow!("missing hardblank"))?;
+        let params = params
+            .as_str()
+            .split_whitespace()
+            .map(|p| p.parse::<i32>())
+            .collect::<Result<Vec<_>, _>>()
+            .context("invalid header")?;
+
+        // old layout is -1 for full width, other fields are counts
+        let [height, _baseline, _max_length, _old_layout, comment_lines, ..] = params[..] else {
+            bail!("invalid header");
+        };
+        let (Ok(height @ 1..), Ok(comment_lines)) = (usize::try_from(height), usize::try_from(comment_lines)) else {
+            bail!("invalid header: height {height}, comment lines {comment_lines}");
+        };
+
+        // glyphs start from space, digits and colon are from 16th
+        let rows = lines
+            .skip(comment_lines)
+            .skip(16 * height)
+            .take(11 * height)
+            .map(|line| {
+                let line = line.trim_end_matches(['\r', '\n']);
+                let endmark = line.chars().last().unwrap_or(' ');
+                line.trim_end_matches(endmark)
+                    .chars()
+                    .map(|c| (c != ' ' && c != hardblank).then_some(c))
+                    .collect()
+            })
+            .collect::<Vec<_>>();
+
+        if rows.len() < 10 * height {
+            bail!("missing glyphs of digits");
+        }
+
+        Self::from_glyphs(rows.chunks(height).map(Glyph::from_rows).collect())
+    }
+
+    fn from_glyphs(mut glyphs: Vec<Glyph>) -> Result<Self> {
+        if glyphs.len() < 10 {
+            bail!("expect glyphs of 0 to 9, found {}", glyphs.len());
+        }
+
+        let height = (glyphs[0].cells.len() / glyphs[0].width.max(1) as usize) as u16;
+        if height == 0 || glyphs.iter().any(|g| g.width == 0 || g.cells.len() != g.width as usize * height as usize) {
+            bail!("all glyphs must have the same height");
+        }
+
+        glyphs.truncate(11);
+        let colon = if glyphs.len() > 10 { glyphs.pop() } else { None };
+
+        Ok(Self {
+            width: glyphs.iter().map(|g| g.width).max().unwrap_or(0),
+            height,
+            digits: glyphs,
+            colon,
+        })
+    }
+}
+
+struct BundledFonts;
+
+impl Display for BundledFonts {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let names = BUNDLED_FONTS.map(|(name, _)| name);
+        names.join(", ").fmt(f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// FIGlet font of 2 rows, glyphs from space to `/` are blank and digits are drawn with their own char
+    fn figlet(header: &str) -> String {
+        let mut text = format!("{header}\ncomment\n");
+        for c in (' '..='/').chain('0'..=':') {
+            let glyph = if c.is_ascii_digit() || c == ':' { c } else { '$' };
+            text.push_str(&format!("{glyph}{glyph}@\n{glyph}$@@\n"));
+        }
+        text
+    }
+
+    #[test]
+    fn figlet_with_full_width_layout() {
+        let font = Font::parse_figlet(&figlet("flf2a$ 2 1 3 -1 1")).unwrap();
+
+        assert_eq!((font.width, font.height), (2, 2));
+        assert_eq!(font.digit(7).get(0, 0), Some('7'));
+        assert_eq!(font.digit(7).get(1, 1), None);
+        assert!(font.colon().is_some());
+    }
+
+    #[test]
+    fn figlet_with_invalid_header() {
+        assert!(Font::parse_figlet(&figlet("flf2a$ 0 1 3 -1 1")).is_err());
+        assert!(Font::parse_figlet(&figlet("flf2a$ 2 1 3 -1 -1")).is_err());
+        assert!(Font::parse_figlet(&figlet("flf2a$ 2 1 3")).is_err());
+    }
+}
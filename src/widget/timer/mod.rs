@@ -0,0 +1,93 @@
+use std::{fmt::Display, rc::Rc};
+
+use anyhow::Result;
+use clap::ValueEnum;
+use ratatui::{
+    buffer::Buffer,
+    layout::{Rect, Size},
+    widgets::Widget,
+};
+
+use crate::{cli::Args, state::timer::TimerState};
+
+use self::{analog::AnalogFace, binary::BinaryFace, color::Paint, digital::DigitalFace, font::{Font, COMPACT_FONT}, words::WordsFace};
+
+pub mod analog;
+pub mod binary;
+pub mod color;
+pub mod digital;
+pub mod font;
+pub mod pixel;
+pub mod progress;
+pub mod words;
+
+/// enum alias for parsed from cli
+#[derive(Copy, Clone, Default, ValueEnum)]
+pub enum ClockFace {
+    #[default]
+    Digital,
+    Analog,
+    Binary,
+    Bcd,
+    Words,
+}
+
+impl Display for ClockFace {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = match *self {
+            ClockFace::Digital => "digital",
+            ClockFace::Analog => "analog",
+            ClockFace::Binary => "binary",
+            ClockFace::Bcd => "bcd",
+            ClockFace::Words => "words",
+        };
+
+        s.fmt(f)
+    }
+}
+
+/// how the timer is drawn, the face decides its own size so timer modes work for any face
+pub trait TimerFace {
+    /// size of the face in a screen of given size
+    fn layout(&self, boundary: Size) -> Size;
+    /// render the face into `timer.state.face_area()`
+    fn render(&self, timer: &Timer, buf: &mut Buffer);
+}
+
+pub fn timer_face(args: &Args) -> Result<Rc<dyn TimerFace>> {
+    Ok(match args.clock {
+        ClockFace::Digital => Rc::new(DigitalFace {
+            font: Font::load(&args.font)?,
+            compact: Font::load(COMPACT_FONT)?,
+            pixel: args.pixel,
+            scale: args.scale,
+            fraction: args.scale_fraction,
+            with_tenths: args.stopwatch,
+            glyphs: args.timer_glyphs,
+        }),
+        ClockFace::Analog => Rc::new(AnalogFace),
+        ClockFace::Binary => Rc::new(BinaryFace { bcd: false }),
+        ClockFace::Bcd => Rc::new(BinaryFace { bcd: true }),
+        ClockFace::Words => Rc::new(WordsFace {
+            lang: args.clock_lang,
+            dim_color: args.dim_color,
+        }),
+    })
+}
+
+pub struct Timer<'a> {
+    pub timer: crate::state::timer::Timer,
+    pub color: Paint,
+    pub state: &'a TimerState,
+    /// fraction of the minute shown by progress
+    pub progress: f32,
+}
+
+impl<'a> Widget for Timer<'a> {
+    fn render(self, _: Rect, buf: &mut Buffer) {
+        self.state.face.render(&self, buf);
+        if let Some(progress) = self.state.progress {
+            progress.render(&self, buf);
+        }
+    }
+}
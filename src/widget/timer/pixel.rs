@@ -0,0 +1,129 @@
+use std::fmt::Display;
+
+use clap::ValueEnum;
+use ratatui::buffer::Buffer;
+
+use super::color::Paint;
+
+const BRAILLE_BASE: u32 = 0x2800;
+/// dot bits of braille pattern by `[y][x]` in a cell
+const BRAILLE_DOTS: [[u8; 2]; 4] = [
+    [0x01, 0x08],
+    [0x02, 0x10],
+    [0x04, 0x20],
+    [0x40, 0x80],
+];
+
+/// how virtual pixels are packed into cells
+#[derive(Copy, Clone, Default, ValueEnum)]
+pub enum PixelMode {
+    /// a pixel per cell
+    #[default]
+    Block,
+    /// 1x2 pixels per cell with half blocks
+    Half,
+    /// 2x4 pixels per cell with braille dots
+    Braille,
+}
+
+impl PixelMode {
+    /// pixels per cell in width and height
+    pub fn cell_size(&self) -> (u16, u16) {
+        match self {
+            PixelMode::Block => (1, 1),
+            PixelMode::Half => (1, 2),
+            PixelMode::Braille => (2, 4),
+        }
+    }
+
+    /// cells taken by pixels
+    pub fn cells(&self, width: u16, height: u16) -> (u16, u16) {
+        let (w, h) = self.cell_size();
+        (width.div_ceil(w), height.div_ceil(h))
+    }
+}
+
+impl Display for PixelMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = match *self {
+            PixelMode::Block => "block",
+            PixelMode::Half => "half",
+            PixelMode::Braille => "braille",
+        };
+
+        s.fmt(f)
+    }
+}
+
+/// virtual pixel grid a glyph is rasterised into, lit pixels keep the char of glyph for block mode
+pub struct PixelGrid {
+    width: u16,
+    height: u16,
+    pixels: Vec<Option<char>>,
+}
+
+impl PixelGrid {
+    pub fn new(width: u16, height: u16) -> Self {
+        Self {
+            width,
+            height,
+            pixels: vec![None; width as usize * height as usize],
+        }
+    }
+
+    pub fn set(&mut self, x: u16, y: u16, c: char) {
+        if x < self.width && y < self.height {
+            self.pixels[y as usize * self.width as usize + x as usize] = Some(c);
+        }
+    }
+
+    fn get(&self, x: u16, y: u16) -> Option<char> {
+        if x < self.width && y < self.height {
+            self.pixels[y as usize * self.width as usize + x as usize]
+        } else {
+            None
+        }
+    }
+
+    fn is_lit(&self, x: u16, y: u16) -> bool {
+        self.get(x, y).is_some()
+    }
+
+    /// pack pixels into cells, cells without lit pixel are left untouched
+    pub fn render(&self, mode: PixelMode, left: u16, top: u16, paint: &Paint, buf: &mut Buffer) {
+        let (pw, ph) = mode.cell_size();
+        let (columns, rows) = mode.cells(self.width, self.height);
+
+        for row in 0..rows {
+            for column in 0..columns {
+                let (px, py) = (column * pw, row * ph);
+                let c = match mode {
+                    PixelMode::Block => self.get(px, py),
+                    PixelMode::Half => match (self.is_lit(px, py), self.is_lit(px, py + 1)) {
+                        (true, true) => Some('█'),
+                        (true, false) => Some('▀'),
+                        (false, true) => Some('▄'),
+                        (false, false) => None,
+                    },
+                    PixelMode::Braille => {
+                        let mut bits = 0;
+                        for (y, dots) in BRAILLE_DOTS.iter().enumerate() {
+                            for (x, dot) in dots.iter().enumerate() {
+                                if self.is_lit(px + x as u16, py + y as u16) {
+                                    bits |= dot;
+                                }
+                            }
+                        }
+
+                        (bits > 0).then(|| char::from_u32(BRAILLE_BASE + bits as u32).unwrap())
+                    }
+                };
+
+                let (x, y) = (left + column, top + row);
+                if let (Some(c), Some(cell)) = (c, buf.cell_mut((x, y))) {
+                    cell.set_char(c).set_fg(paint.at(x, y));
+                }
+            }
+        }
+    }
+}
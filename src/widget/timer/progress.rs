@@ -0,0 +1,133 @@
+use std::fmt::Display;
+
+use clap::ValueEnum;
+use ratatui::{
+    buffer::Buffer,
+    layout::{Rect, Size},
+    style::Modifier,
+};
+
+use super::{color::Paint, Timer};
+
+const BAR_LIT: char = '━';
+const BAR_HALF: char = '╸';
+const BAR_UNLIT: char = '─';
+
+/// progress of seconds in the minute around the timer face
+#[derive(Copy, Clone, ValueEnum)]
+pub enum Progress {
+    /// a thin bar under the face
+    Bar,
+    /// a ring around the face lit clockwise from the top
+    Ring,
+}
+
+impl Display for Progress {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = match *self {
+            Progress::Bar => "bar",
+            Progress::Ring => "ring",
+        };
+
+        s.fmt(f)
+    }
+}
+
+impl Progress {
+    /// cells taken in addition to the face
+    pub fn extra(&self) -> Size {
+        match self {
+            // a blank row and the bar
+            Progress::Bar => Size { width: 0, height: 2 },
+            // border and a padding column on each side
+            Progress::Ring => Size { width: 4, height: 2 },
+        }
+    }
+
+    /// area of the face in area of the timer
+    pub fn inner(&self, area: Rect) -> Rect {
+        match self {
+            Progress::Bar => Rect {
+                height: area.height.saturating_sub(2),
+                ..area
+            },
+            Progress::Ring => Rect {
+                x: area.x + 2,
+                y: area.y + 1,
+                width: area.width.saturating_sub(4),
+                height: area.height.saturating_sub(2),
+            },
+        }
+    }
+
+    pub fn render(&self, timer: &Timer, buf: &mut Buffer) {
+        let area = timer.state.area;
+        let fraction = timer.progress;
+
+        match self {
+            Progress::Bar => Self::render_bar(area, fraction, &timer.color, buf),
+            Progress::Ring => Self::render_ring(area, fraction, &timer.color, buf),
+        }
+    }
+
+    /// half cells give smoothing within a cell
+    fn render_bar(area: Rect, fraction: f32, paint: &Paint, buf: &mut Buffer) {
+        let y = area.bottom().saturating_sub(1);
+        let halves = (fraction * area.width as f32 * 2.) as u16;
+
+        for i in 0..area.width {
+            let x = area.x + i;
+            let c = match halves.saturating_sub(i * 2) {
+                0 => BAR_UNLIT,
+                1 => BAR_HALF,
+                _ => BAR_LIT,
+            };
+
+            Self::set_cell(x, y, c, c != BAR_UNLIT, paint, buf);
+        }
+    }
+
+    fn render_ring(area: Rect, fraction: f32, paint: &Paint, buf: &mut Buffer) {
+        if area.width < 2 || area.height < 2 {
+            return;
+        }
+
+        let (left, top) = (area.x, area.y);
+        let (right, bottom) = (area.right() - 1, area.bottom() - 1);
+        let center = left + area.width / 2;
+
+        // cells of the ring clockwise from the top center
+        let cells = (center..right)
+            .map(|x| (x, top))
+            .chain((top..bottom).map(|y| (right, y)))
+            .chain((left + 1..=right).rev().map(|x| (x, bottom)))
+            .chain((top + 1..=bottom).rev().map(|y| (left, y)))
+            .chain((left..center).map(|x| (x, top)))
+            .collect::<Vec<_>>();
+        let lit = (fraction * cells.len() as f32) as usize;
+
+        for (i, (x, y)) in cells.into_iter().enumerate() {
+            let is_lit = i < lit;
+            let c = match ((x == left, x == right), (y == top, y == bottom)) {
+                ((true, _), (true, _)) => if is_lit { '┏' } else { '┌' },
+                ((_, true), (true, _)) => if is_lit { '┓' } else { '┐' },
+                ((true, _), (_, true)) => if is_lit { '┗' } else { '└' },
+                ((_, true), (_, true)) => if is_lit { '┛' } else { '┘' },
+                (_, (true, _)) | (_, (_, true)) => if is_lit { '━' } else { '─' },
+                _ => if is_lit { '┃' } else { '│' },
+            };
+
+            Self::set_cell(x, y, c, is_lit, paint, buf);
+        }
+    }
+
+    /// unlit cells are dimmed, cells out of screen are skipped
+    fn set_cell(x: u16, y: u16, c: char, is_lit: bool, paint: &Paint, buf: &mut Buffer) {
+        if let Some(cell) = buf.cell_mut((x, y)) {
+            cell.set_char(c).set_fg(paint.at(x, y));
+            if !is_lit {
+                cell.modifier.insert(Modifier::DIM);
+            }
+        }
+    }
+}
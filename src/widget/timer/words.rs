@@ -0,0 +1,208 @@
+use std::fmt::Display;
+
+use clap::ValueEnum;
+use ratatui::{
+    buffer::Buffer,
+    layout::Size,
+    style::{Color, Style},
+};
+
+use super::{Timer, TimerFace};
+
+const LIT_DOT: &str = "●";
+const UNLIT_DOT: &str = "·";
+
+/// letters of the word clock grid and `(row, column, length)` of each lit word
+type Span = (u16, u16, u16);
+
+/// enum alias for parsed from cli
+#[derive(Copy, Clone, Default, ValueEnum)]
+pub enum WordLang {
+    #[default]
+    En,
+    Ja,
+}
+
+impl Display for WordLang {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = match *self {
+            WordLang::En => "en",
+            WordLang::Ja => "ja",
+        };
+
+        s.fmt(f)
+    }
+}
+
+const EN_GRID: [&str; 10] = [
+    "ITLISASAMPM",
+    "ACQUARTERDC",
+    "TWENTYFIVEX",
+    "HALFSTENFTO",
+    "PASTERUNINE",
+    "ONESIXTHREE",
+    "FOURFIVETWO",
+    "EIGHTELEVEN",
+    "SEVENTWELVE",
+    "TENSEOCLOCK",
+];
+
+/// ONE to TWELVE
+const EN_HOURS: [Span; 12] = [
+    (5, 0, 3), (6, 8, 3), (5, 6, 5), (6, 0, 4), (6, 4, 4), (5, 3, 3),
+    (8, 0, 5), (7, 0, 5), (4, 7, 4), (9, 0, 3), (7, 5, 6), (8, 5, 6),
+];
+
+const JA_GRID: [&str; 4] = [
+    "午前後十一二",
+    "三四五六七八",
+    "九時二三四五",
+    "十五半分です",
+];
+
+/// 一 to 十二
+const JA_HOURS: [&[Span]; 12] = [
+    &[(0, 4, 1)], &[(0, 5, 1)], &[(1, 0, 1)], &[(1, 1, 1)], &[(1, 2, 1)], &[(1, 3, 1)],
+    &[(1, 4, 1)], &[(1, 5, 1)], &[(2, 0, 1)], &[(0, 3, 1)], &[(0, 3, 2)], &[(0, 3, 1), (0, 5, 1)],
+];
+
+/// time as a grid of letters with the words of current time lit up, minutes are rounded down to
+/// five and the remainder is shown as dots under the grid
+pub struct WordsFace {
+    pub lang: WordLang,
+    pub dim_color: Color,
+}
+
+impl WordsFace {
+    fn grid(&self) -> &'static [&'static str] {
+        match self.lang {
+            WordLang::En => &EN_GRID,
+            WordLang::Ja => &JA_GRID,
+        }
+    }
+
+    /// cells taken by a letter and the empty rows between letters
+    fn slot(&self) -> (u16, u16) {
+        match self.lang {
+            WordLang::En => (2, 0),
+            WordLang::Ja => (3, 1),
+        }
+    }
+
+    fn en_words(hours: u8, minutes: u8) -> Vec<Span> {
+        const PAST: Span = (4, 0, 4);
+        const TO: Span = (3, 9, 2);
+        const FIVE: Span = (2, 6, 4);
+        const TEN: Span = (3, 5, 3);
+        const QUARTER: [Span; 2] = [(1, 0, 1), (1, 2, 7)];
+        const TWENTY: Span = (2, 0, 6);
+
+        let mut words = vec![(0, 0, 2), (0, 3, 2)];
+        let m5 = minutes / 5;
+        words.extend_from_slice(match m5 {
+            0 => &[(9, 5, 6)],
+            1 | 11 => &[FIVE],
+            2 | 10 => &[TEN],
+            3 | 9 => &QUARTER,
+            4 | 8 => &[TWENTY],
+            5 | 7 => &[TWENTY, FIVE],
+            _ => &[(3, 0, 4)],
+        });
+
+        match m5 {
+            0 => (),
+            1..=6 => words.push(PAST),
+            _ => words.push(TO),
+        }
+
+        let hours = if m5 > 6 { hours + 1 } else { hours };
+        words.push(EN_HOURS[(hours as usize + 11) % 12]);
+        words
+    }
+
+    fn ja_words(hours: u8, minutes: u8) -> Vec<Span> {
+        const TEN: Span = (3, 0, 1);
+        const FIVE: Span = (3, 1, 1);
+        const MINUTE: Span = (3, 3, 1);
+
+        let mut words = vec![(0, 0, 1), if hours % 24 < 12 { (0, 1, 1) } else { (0, 2, 1) }];
+        words.extend_from_slice(JA_HOURS[(hours as usize + 11) % 12]);
+        words.push((2, 1, 1));
+
+        let m5 = minutes / 5;
+        if m5 == 6 {
+            words.push((3, 2, 1));
+        } else if m5 > 0 {
+            // tens of 二十 to 五十 are on third row
+            if m5 >= 4 {
+                words.push((2, m5 as u16 / 2, 1));
+            }
+
+            if m5 >= 2 {
+                words.push(TEN);
+            }
+
+            if m5 % 2 == 1 {
+                words.push(FIVE);
+            }
+
+            words.push(MINUTE);
+        }
+
+        words.push((3, 4, 2));
+        words
+    }
+}
+
+impl TimerFace for WordsFace {
+    fn layout(&self, _: Size) -> Size {
+        let grid = self.grid();
+        let (slot_width, row_gap) = self.slot();
+        let rows = grid.len() as u16;
+        let columns = grid[0].chars().count() as u16;
+
+        Size {
+            width: columns * slot_width - 1,
+            // and a row of minute dots
+            height: rows + (rows - 1) * row_gap + 2,
+        }
+    }
+
+    fn render(&self, timer: &Timer, buf: &mut Buffer) {
+        let area = timer.state.face_area();
+        let (slot_width, row_gap) = self.slot();
+        let t = timer.timer;
+        let words = match self.lang {
+            WordLang::En => Self::en_words(t.hours, t.minutes),
+            WordLang::Ja => Self::ja_words(t.hours, t.minutes),
+        };
+
+        let lit = |x: u16, y: u16| Style::new().fg(timer.color.at(x, y)).bold();
+        let dim = Style::new().fg(self.dim_color);
+        let mut set_symbol = |x: u16, y: u16, symbol: &str, style: Style| {
+            if x + slot_width - 1 <= buf.area.right() && y < buf.area.bottom() && x >= buf.area.left() && y >= buf.area.top() {
+                buf.set_string(x, y, symbol, style);
+            }
+        };
+
+        let mut y = area.top();
+        for (row, letters) in self.grid().iter().enumerate() {
+            for (column, letter) in letters.chars().enumerate() {
+                let (row, column) = (row as u16, column as u16);
+                let is_lit = words.iter().any(|(r, c, len)| *r == row && (*c..c + len).contains(&column));
+                let x = area.left() + column * slot_width;
+                set_symbol(x, y, letter.encode_utf8(&mut [0; 4]), if is_lit { lit(x, y) } else { dim });
+            }
+
+            y += 1 + row_gap;
+        }
+
+        let dots = t.minutes % 5;
+        let left = area.left() + (area.width.saturating_sub(7)) / 2;
+        for i in 0..4u8 {
+            let (x, y) = (left + i as u16 * 2, y - row_gap + 1);
+            let (dot, style) = if i < dots { (LIT_DOT, lit(x, y)) } else { (UNLIT_DOT, dim) };
+            set_symbol(x, y, dot, style);
+        }
+    }
+}
@@ -1,15 +1,96 @@
 use ratatui::style::Color;
+use tinyvec::ArrayVec;
 
-use crate::state::{tail::TailMode, wind::WindDirection, Cell, CellType};
+use crate::{
+    state::{tail::TailMode, wind::WindDirection, Cell, CellType, Mode, Particle},
+    theme::default_styles,
+};
 
 use super::WeatherWidgetImpl;
 
+/// glyphs picked at random for each particle
+pub type GlyphSet = ArrayVec<[char; 8]>;
+
+/// color and glyphs of a mode, from the theme and command line
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub struct WeatherStyle {
+    pub color: Color,
+    /// glyphs of fast, normal and slow cells
+    pub glyphs: [char; 3],
+    /// glyphs to the left, right and straight, for tails of meteor and slow rain in wind
+    pub tails: [char; 3],
+    /// replaces glyphs of fast, normal and slow cells when not empty
+    pub variants: GlyphSet,
+}
+
+#[derive(Copy, Clone)]
+pub struct WeatherStyles {
+    pub rain: WeatherStyle,
+    pub snow: WeatherStyle,
+    pub meteor: WeatherStyle,
+}
+
+/// styles of the bundled default theme
+impl Default for WeatherStyles {
+    fn default() -> Self {
+        default_styles()
+    }
+}
+
+impl WeatherStyle {
+    /// nothing drawn, the bundled default theme is parsed over it
+    pub fn blank() -> Self {
+        Self {
+            color: Color::Reset,
+            glyphs: [' '; 3],
+            tails: [' '; 3],
+            variants: GlyphSet::new(),
+        }
+    }
+
+    /// glyph of a moving particle, by speed or by its variant
+    fn glyph(&self, speed: usize, variant: u8) -> char {
+        match self.variants.len() {
+            0 => self.glyphs[speed],
+            len => self.variants[variant as usize % len],
+        }
+    }
+}
+
+impl WeatherStyles {
+    pub fn blank() -> Self {
+        Self {
+            rain: WeatherStyle::blank(),
+            snow: WeatherStyle::blank(),
+            meteor: WeatherStyle::blank(),
+        }
+    }
+
+    pub fn get(&self, mode: Mode) -> WeatherStyle {
+        match mode {
+            Mode::Rain => self.rain,
+            Mode::Snow => self.snow,
+            Mode::Meteor => self.meteor,
+            Mode::Disable => WeatherStyle::blank(),
+        }
+    }
+
+    pub fn get_mut(&mut self, mode: Mode) -> Option<&mut WeatherStyle> {
+        match mode {
+            Mode::Rain => Some(&mut self.rain),
+            Mode::Snow => Some(&mut self.snow),
+            Mode::Meteor => Some(&mut self.meteor),
+            Mode::Disable => None,
+        }
+    }
+}
+
 #[allow(dead_code)]
 #[derive(Copy, Clone, PartialEq, Eq)]
 pub enum GeneralWeatherWidget {
-    Rain(WindDirection),
-    Snow,
-    Meteor(TailMode),
+    Rain(WindDirection, WeatherStyle),
+    Snow(WeatherStyle),
+    Meteor(TailMode, WeatherStyle),
     Star,
     Disable,
 }
@@ -18,38 +99,41 @@ impl WeatherWidgetImpl for GeneralWeatherWidget {
     fn get_color(&self, cell: CellType) -> Color {
         use CellType::*;
         match self {
-            Self::Rain(_) => Color::Rgb(150, 150, 150),
-            Self::Meteor(_) => match cell {
-                Fast | Normal | Slow => Color::Yellow,
+            Self::Rain(_, style) | Self::Snow(style) => style.color,
+            Self::Meteor(_, style) => match cell {
+                Fast | Normal | Slow => style.color,
                 _ => Color::Reset,
             }
             _ => Color::Reset,
         }
     }
 
-    fn get_char(&self, d: CellType) -> char {
+    fn get_char(&self, particle: Particle) -> char {
         use CellType::*;
+        let Particle { kind, variant } = particle;
         match self {
-            Self::Rain(wind) => match d {
-                Fast => '.',
-                Normal => ':',
+            Self::Rain(wind, style) => match kind {
+                Fast => style.glyph(0, variant),
+                Normal => style.glyph(1, variant),
                 Slow => match wind {
-                    WindDirection::Left => '/',
-                    WindDirection::Right => '\\',
-                    WindDirection::None => '|',
+                    WindDirection::Left => style.tails[0],
+                    WindDirection::Right => style.tails[1],
+                    WindDirection::None => style.glyph(2, variant),
                 }
                 _ => ' ',
             }
-            Self::Snow => match d {
-                CellType::Normal => '●',
+            Self::Snow(style) => match kind {
+                CellType::Normal => style.glyph(1, variant),
                 _ => ' ',
             }
-            Self::Meteor(tail) => match d {
-                Fast | Normal | Slow => '★',
+            Self::Meteor(tail, style) => match kind {
+                Fast => style.glyph(0, variant),
+                Normal => style.glyph(1, variant),
+                Slow => style.glyph(2, variant),
                 Tail => match tail {
-                    TailMode::Left => '/',
-                    TailMode::Right => '\\',
-                    TailMode::Default => '|',
+                    TailMode::Left => style.tails[0],
+                    TailMode::Right => style.tails[1],
+                    TailMode::Default => style.tails[2],
                 },
                 _ => ' ',
             }
@@ -57,26 +141,18 @@ impl WeatherWidgetImpl for GeneralWeatherWidget {
         }
     }
 
-    fn get_render_cell_type(&self, cell: &Cell) -> CellType {
+    fn get_render_particle(&self, cell: &Cell) -> Particle {
         if *self == Self::Disable {
-            return CellType::None;
+            return Particle::default();
         }
 
+        let find = |kind: CellType| cell.iter().find(|p| p.kind == kind).copied();
         match self {
-            Self::Snow => if !cell.is_empty() && cell.contains(&CellType::Normal) {
-                CellType::Normal
-            } else {
-                CellType::None
-            },
-
-            _ => if cell.contains(&CellType::Slow) {
-                CellType::Slow
-            } else if !cell.is_empty() {
-                *cell.first().unwrap()
-            } else {
-                CellType::None
-            },
+            Self::Snow(_) => find(CellType::Normal).unwrap_or_default(),
+
+            _ => find(CellType::Slow)
+                .or(cell.first().copied())
+                .unwrap_or_default(),
         }
     }
 }
-